@@ -3,3 +3,15 @@
     run bash -c 'echo "feat(cli): impl -a flag" | commitlint --config not-existing-config.js'
     [ "$status" -eq 1 ]
 }
+
+# bats test_tags=cli
+@test "revert sha empty" {
+    config="$BATS_TEST_TMPDIR/.commitlintrc.yml"
+    printf 'rules:\n  revert-sha-empty:\n    level: error\n' > "$config"
+
+    run bash -c "echo 'Revert \"feat(cli): impl -a flag\"' | commitlint --config $config"
+    [ "$status" -eq 1 ]
+
+    run bash -c "printf 'Revert \"feat(cli): impl -a flag\"\n\nThis reverts commit 1234567890abcdef1234567890abcdef12345678.\n' | commitlint --config $config"
+    [ "$status" -eq 0 ]
+}
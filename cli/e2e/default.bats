@@ -83,3 +83,15 @@ Make it smart. But we should not be opinionated so the default is ignored.\" | c
     run bash -c 'echo "feat(other): add script" | commitlint'
     [ "$status" -eq 0 ]
 }
+
+# bats test_tags=default
+@test "default ignores" {
+    run bash -c "echo \"Merge branch 'feature' into main\" | commitlint"
+    [ "$status" -eq 0 ]
+}
+
+# bats test_tags=default
+@test "default ignores revert" {
+    run bash -c "echo 'Revert \"feat(cli): impl -a flag\"' | commitlint"
+    [ "$status" -eq 0 ]
+}
@@ -6,8 +6,9 @@ use std::{
 
 use clap::Parser;
 
-use crate::git::{self, ReadCommitMessageOptions};
-use crate::message::Message;
+use commitlint_rs::config::{Cleanup, Config};
+use commitlint_rs::git::{self, ReadCommitMessageOptions};
+use commitlint_rs::message::Message;
 
 /// Cli represents the command line arguments.
 ///
@@ -49,43 +50,97 @@ impl Args {
         !stdin().is_terminal()
     }
 
+    /// Clean up the commit message following the cleanup mode of the config.
+    ///
+    /// `edited` tells whether the message is read from the file to be edited
+    /// such as `COMMIT_EDITMSG`, which is used to resolve [`Cleanup::Default`].
+    fn cleanup(&self, msg: String, config: &Config, edited: bool) -> String {
+        let mode = match config.cleanup {
+            Cleanup::Default if edited => Cleanup::Strip,
+            Cleanup::Default => Cleanup::Whitespace,
+            mode => mode,
+        };
+
+        let comment = match mode {
+            Cleanup::Scissors | Cleanup::Strip => git::comment_char(&self.cwd, &msg),
+            _ => String::new(),
+        };
+
+        git::cleanup(&msg, mode, &comment)
+    }
+
+    /// Read the staged files as the files changed by the commit being edited
+    /// if any of the rules needs them.
+    fn staged_files(&self, config: &Config) -> Option<Vec<String>> {
+        config
+            .rules
+            .needs_changed_files()
+            .then(|| git::staged_files(&self.cwd))
+    }
+
     /// Read commit messages from stdin.
-    pub fn read(&self) -> Result<Vec<Message>, Error> {
+    pub fn read(&self, config: &Config) -> Result<Vec<Message>, Error> {
+        let parser = config.parser.header_parser().map_err(|_| Error)?;
+        let reference_parser = config.parser.reference_parser().map_err(|_| Error)?;
+
         // Check first whether or not the --edit option was supplied. When running from tooling such as
         // `pre-commit`, stdin exists, so this needs to come first.
         if let Some(edit) = self.edit.as_deref() {
             if edit != "false" {
                 let msg = std::fs::read_to_string(edit)
                     .unwrap_or_else(|_| panic!("Failed to read commit message from {}", edit));
-                return Ok(vec![Message::new(msg)]);
+                return Ok(vec![Message {
+                    changed_files: self.staged_files(config),
+                    ..Message::with_parser(
+                        self.cleanup(msg, config, true),
+                        &parser,
+                        &reference_parser,
+                    )
+                }]);
             }
         }
 
-        // Otherwise, check for stdin and use the incoming text buffer from there if so.
-        if self.has_stdin() {
-            let mut buffer = String::new();
-            stdin()
-                .read_to_string(&mut buffer)
-                .expect("Failed to read commit messages from stdin");
-            return Ok(vec![Message::new(buffer)]);
-        }
-
         if self.from.is_some() || self.to.is_some() {
-            // Reading directly from Git if from or to is specified.
-            let config = ReadCommitMessageOptions {
+            // Reading directly from Git if from or to is specified. This needs to come before stdin
+            // because stdin also exists when running on CI.
+            let options = ReadCommitMessageOptions {
                 from: self.from.clone(),
                 path: self.cwd.clone(),
                 to: self.to.clone(),
             };
 
-            let messages = git::read(config)
-                .iter()
-                .map(|s| Message::new(s.to_string()))
+            let messages = git::read(options)
+                .into_iter()
+                .map(|(commit, msg)| Message {
+                    changed_files: config
+                        .rules
+                        .needs_changed_files()
+                        .then(|| git::changed_files(&self.cwd, &commit.sha)),
+                    commit: Some(commit),
+                    ..Message::with_parser(
+                        self.cleanup(msg, config, false),
+                        &parser,
+                        &reference_parser,
+                    )
+                })
                 .collect();
 
             return Ok(messages);
         }
 
+        // Otherwise, check for stdin and use the incoming text buffer from there if so.
+        if self.has_stdin() {
+            let mut buffer = String::new();
+            stdin()
+                .read_to_string(&mut buffer)
+                .expect("Failed to read commit messages from stdin");
+            return Ok(vec![Message::with_parser(
+                self.cleanup(buffer, config, false),
+                &parser,
+                &reference_parser,
+            )]);
+        }
+
         // Use git::edit_msg_path to correctly resolve COMMIT_EDITMSG, supporting git worktrees.
         let default_path = git::edit_msg_path(&self.cwd);
         let msg = std::fs::read_to_string(&default_path).unwrap_or_else(|_| {
@@ -94,6 +149,9 @@ impl Args {
                 default_path.display()
             )
         });
-        Ok(vec![Message::new(msg)])
+        Ok(vec![Message {
+            changed_files: self.staged_files(config),
+            ..Message::with_parser(self.cleanup(msg, config, true), &parser, &reference_parser)
+        }])
     }
 }
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Case represents the letter case of a part of the commit message.
+///
+/// It follows the cases of `@commitlint/ensure` so that the same configuration works.
+/// See: https://github.com/conventional-changelog/commitlint/blob/master/%40commitlint/ensure/src/case.ts
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Case {
+    /// `fooBar`
+    #[serde(rename = "camel-case")]
+    Camel,
+
+    /// `foo-bar`
+    #[serde(rename = "kebab-case")]
+    Kebab,
+
+    /// `foo bar`
+    #[serde(rename = "lower-case")]
+    Lower,
+
+    /// `FooBar`
+    #[serde(rename = "pascal-case")]
+    Pascal,
+
+    /// `Foo bar`
+    #[serde(rename = "sentence-case")]
+    Sentence,
+
+    /// `foo_bar`
+    #[serde(rename = "snake-case")]
+    Snake,
+
+    /// `Foo Bar`
+    #[serde(rename = "start-case")]
+    Start,
+
+    /// `FOO BAR`
+    #[serde(rename = "upper-case")]
+    Upper,
+}
+
+impl Case {
+    /// Check if the text is in the case.
+    /// Note that the characters without the case such as digits are ignored,
+    /// and the empty text is in none of the cases.
+    pub fn is(&self, text: &str) -> bool {
+        if text.is_empty() {
+            return false;
+        }
+
+        match self {
+            Case::Camel => !text.starts_with(char::is_uppercase) && is_joined(text),
+            Case::Kebab => is_delimited(text, '-'),
+            Case::Lower => !text.chars().any(char::is_uppercase),
+            Case::Pascal => !text.starts_with(char::is_lowercase) && is_joined(text),
+            Case::Sentence => {
+                let mut first = text.split_whitespace().next().unwrap_or_default().chars();
+                !first.next().is_some_and(char::is_lowercase) && !first.any(char::is_uppercase)
+            }
+            Case::Snake => is_delimited(text, '_'),
+            Case::Start => text
+                .split_whitespace()
+                .all(|word| !word.starts_with(char::is_lowercase)),
+            Case::Upper => !text.chars().any(char::is_lowercase),
+        }
+    }
+}
+
+/// Check if the text consists of the capitalized words joined without delimiters such as `fooBar`.
+/// Note that the consecutive upper case letters such as `FOO` are not capitalized words.
+fn is_joined(text: &str) -> bool {
+    text.chars().all(char::is_alphanumeric)
+        && !text
+            .chars()
+            .zip(text.chars().skip(1))
+            .any(|(a, b)| a.is_uppercase() && b.is_uppercase())
+}
+
+/// Check if the text consists of the lower case words joined by the delimiter.
+fn is_delimited(text: &str, delimiter: char) -> bool {
+    text.split(delimiter).all(|word| {
+        !word.is_empty()
+            && word
+                .chars()
+                .all(|c| c.is_alphanumeric() && !c.is_uppercase())
+    })
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Case::Camel => write!(f, "camel-case"),
+            Case::Kebab => write!(f, "kebab-case"),
+            Case::Lower => write!(f, "lower-case"),
+            Case::Pascal => write!(f, "pascal-case"),
+            Case::Sentence => write!(f, "sentence-case"),
+            Case::Snake => write!(f, "snake-case"),
+            Case::Start => write!(f, "start-case"),
+            Case::Upper => write!(f, "upper-case"),
+        }
+    }
+}
+
+/// Format the cases for the violation message such as `lower-case, kebab-case`.
+pub fn format_cases(cases: &[Case]) -> String {
+    cases
+        .iter()
+        .map(Case::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert!(Case::Camel.is("fooBar"));
+        assert!(Case::Camel.is("foo"));
+        assert!(!Case::Camel.is("FooBar"));
+        assert!(!Case::Camel.is("foo-bar"));
+        assert!(!Case::Camel.is("fooBAR"));
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        assert!(Case::Kebab.is("foo-bar"));
+        assert!(Case::Kebab.is("foo2"));
+        assert!(!Case::Kebab.is("foo_bar"));
+        assert!(!Case::Kebab.is("Foo-bar"));
+        assert!(!Case::Kebab.is("foo--bar"));
+        assert!(!Case::Kebab.is("-foo"));
+    }
+
+    #[test]
+    fn test_lower_case() {
+        assert!(Case::Lower.is("add new flag"));
+        assert!(Case::Lower.is("日本語"));
+        assert!(!Case::Lower.is("add New flag"));
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert!(Case::Pascal.is("FooBar"));
+        assert!(!Case::Pascal.is("fooBar"));
+        assert!(!Case::Pascal.is("Foo Bar"));
+        assert!(Case::Pascal.is("Foo2Bar"));
+        assert!(!Case::Pascal.is("FOO"));
+        assert!(!Case::Pascal.is("FOO_BAR"));
+        assert!(!Case::Pascal.is("HTTPServer"));
+    }
+
+    #[test]
+    fn test_sentence_case() {
+        assert!(Case::Sentence.is("Add new flag"));
+        assert!(Case::Sentence.is("Add API"));
+        assert!(Case::Sentence.is("Add"));
+        assert!(!Case::Sentence.is("add new flag"));
+        assert!(!Case::Sentence.is("ADD NEW FLAG"));
+        assert!(!Case::Sentence.is("AdD new flag"));
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert!(Case::Snake.is("foo_bar"));
+        assert!(!Case::Snake.is("foo-bar"));
+        assert!(!Case::Snake.is("Foo_bar"));
+    }
+
+    #[test]
+    fn test_start_case() {
+        assert!(Case::Start.is("Add New Flag"));
+        assert!(!Case::Start.is("Add new flag"));
+    }
+
+    #[test]
+    fn test_upper_case() {
+        assert!(Case::Upper.is("ADD NEW FLAG"));
+        assert!(!Case::Upper.is("Add new flag"));
+    }
+
+    #[test]
+    fn test_empty() {
+        for case in [
+            Case::Camel,
+            Case::Kebab,
+            Case::Lower,
+            Case::Pascal,
+            Case::Sentence,
+            Case::Snake,
+            Case::Start,
+            Case::Upper,
+        ] {
+            assert!(!case.is(""), "{} matches empty text", case);
+        }
+    }
+
+    #[test]
+    fn test_format_cases() {
+        assert_eq!(
+            format_cases(&[Case::Lower, Case::Kebab]),
+            "lower-case, kebab-case"
+        );
+    }
+}
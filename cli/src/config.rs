@@ -1,8 +1,15 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::{fs, path::PathBuf};
+use std::sync::LazyLock;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
-use crate::rule::Rules;
+use crate::git::{HeaderParser, ReferenceParser, DEFAULT_HEADER_PATTERN, DEFAULT_SCOPE_DELIMITERS};
+use crate::message::Message;
+use crate::rule::{Level, Pattern, Rule, Rules};
 
 /// Default Root config file path to search for.
 const DEFAULT_CONFIG_ROOT: &str = ".";
@@ -17,14 +24,256 @@ const DEFAULT_CONFIG_FILE: [&str; 4] = [
     ".commitlintrc.yml",
 ];
 
+/// Default patterns of the subjects to be ignored.
+/// These are the commits generated by git or the hosting services.
+///
+/// Inspired by https://github.com/conventional-changelog/commitlint/blob/master/%40commitlint/is-ignored/src/defaults.ts
+const DEFAULT_IGNORES: [&str; 11] = [
+    r"^Merge pull request",
+    r"^Merge (.*?) into (.*?)$",
+    r"^Merge branch (.*?)$",
+    r"^Merge tag (.*?)$",
+    r"^Merge remote-tracking branch",
+    r"^Merged (.*?)(in|into) (.*)$",
+    r"^Merged PR (.*): (.*)$",
+    r"^Automatic merge",
+    r"^Auto-merged (.*?) into (.*)$",
+    r"^(amend|fixup|squash)! ",
+    r"^Initial commit$",
+];
+
+/// Default pattern of the subjects of the commits generated by `git revert`.
+/// It is not ignored if the revert commits are linted such as by the revert-sha-empty rule.
+const DEFAULT_REVERT_IGNORE: &str = r#"^Revert ".*"$"#;
+
 /// Config represents the configuration of commitlint.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Config {
+    /// Cleanup represents how to clean up the commit message before linting.
+    #[serde(default)]
+    pub cleanup: Cleanup,
+
+    /// Default ignores represents whether to ignore the commits generated by
+    /// git or the hosting services such as merge, revert and fixup commits.
+    /// If it is not specified, it is enabled.
+    #[serde(rename = "default-ignores")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ignores: Option<bool>,
+
+    /// Ignores represents the regex patterns of the commit messages to be ignored.
+    /// Note that the patterns are matched against the whole commit message.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignores: Vec<Pattern>,
+
+    /// Parser represents how to parse the commit message.
+    #[serde(default)]
+    pub parser: Parser,
+
     /// Rules represents the rules of commitlint.
     pub rules: Rules,
 }
 
+/// Parser represents how to parse the commit message.
+///
+/// It is similar to the `parserOpts` of conventional-changelog/commitlint.
+/// See: https://commitlint.js.org/reference/configuration.html#parser-presets
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Parser {
+    /// Preset represents the built-in header pattern.
+    /// It is ignored if the header pattern is specified.
+    #[serde(default)]
+    pub preset: Preset,
+
+    /// Header pattern represents the regex to parse the subject.
+    /// The parts are captured by the named groups `type`, `scope`, `description` and `breaking`.
+    #[serde(rename = "header-pattern")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_pattern: Option<String>,
+
+    /// Header correspondence represents the names of the parts corresponding to
+    /// the numbered groups of the header pattern in order.
+    #[serde(rename = "header-correspondence")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_correspondence: Option<Vec<String>>,
+
+    /// Revert header represents whether to parse the reverted header of the
+    /// commits generated by `git revert` so that it is linted with the rules.
+    /// If it is not specified, it is enabled.
+    #[serde(rename = "revert-header")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_header: Option<bool>,
+
+    /// Ticket patterns represents the regexes of the ticket keys such as `PROJ-\d+`
+    /// which are extracted as the references in addition to `#123`.
+    #[serde(default)]
+    #[serde(rename = "ticket-patterns")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ticket_patterns: Vec<String>,
+
+    /// Scope delimiters represents the delimiters of the multiple scopes such as `feat(api,cli): ...`.
+    /// If it is not specified, `,`, `/` and `|` are used.
+    #[serde(rename = "scope-delimiters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_delimiters: Option<Vec<String>>,
+
+    /// Nested scopes represents whether `/` is the separator of the nested scope path
+    /// such as `api/users` instead of the delimiter of the multiple scopes.
+    #[serde(rename = "nested-scopes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nested_scopes: Option<bool>,
+}
+
+impl Parser {
+    /// Build the header parser from the header pattern or the preset.
+    pub fn header_parser(&self) -> Result<HeaderParser, String> {
+        let pattern = self
+            .header_pattern
+            .as_deref()
+            .unwrap_or(self.preset.header_pattern());
+        let correspondence = self.header_correspondence.clone().unwrap_or_default();
+
+        let mut scope_delimiters = self.scope_delimiters.clone().unwrap_or_else(|| {
+            DEFAULT_SCOPE_DELIMITERS
+                .iter()
+                .map(|delimiter| delimiter.to_string())
+                .collect()
+        });
+        if self.nested_scopes.unwrap_or(false) {
+            scope_delimiters.retain(|delimiter| delimiter != "/");
+        }
+
+        HeaderParser::new(pattern, correspondence, self.revert_header.unwrap_or(true))
+            .map(|parser| parser.with_scope_delimiters(scope_delimiters))
+            .map_err(|err| format!("Invalid header pattern: {}", err))
+    }
+
+    /// Build the reference parser from the ticket patterns.
+    pub fn reference_parser(&self) -> Result<ReferenceParser, String> {
+        ReferenceParser::new(&self.ticket_patterns)
+            .map_err(|err| format!("Invalid ticket pattern: {}", err))
+    }
+}
+
+/// Preset represents the built-in header pattern of the well-known conventions.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Preset {
+    /// Angular convention such as `feat(cli): add new flag`.
+    /// Unlike `conventional`, the type can be empty and the space after the colon is required.
+    /// See: https://github.com/angular/angular/blob/main/contributing-docs/commit-message-guidelines.md
+    #[serde(rename = "angular")]
+    Angular,
+
+    /// Conventional Commits such as `feat(cli)!: add new flag`.
+    /// See: https://www.conventionalcommits.org/en/v1.0.0/
+    #[default]
+    #[serde(rename = "conventional")]
+    Conventional,
+
+    /// ESLint convention such as `Fix: semi rule`.
+    /// See: https://eslint.org/docs/latest/contribute/pull-requests#commit-messages
+    #[serde(rename = "eslint")]
+    Eslint,
+
+    /// Gitmoji convention such as `:sparkles: add new flag` or `✨ (cli): add new flag`.
+    /// The emoji is parsed as the type.
+    /// See: https://gitmoji.dev/
+    #[serde(rename = "gitmoji")]
+    Gitmoji,
+}
+
+impl Preset {
+    /// Get the header pattern of the preset.
+    pub fn header_pattern(&self) -> &'static str {
+        match self {
+            Preset::Angular => {
+                r"^(?P<type>\w*)(?:\((?P<scope>.*)\))?(?P<breaking>!)?: (?P<description>.*)$"
+            }
+            Preset::Conventional => DEFAULT_HEADER_PATTERN,
+            Preset::Eslint => r"^(?P<type>\w*):\s*(?P<description>.*)$",
+            Preset::Gitmoji => {
+                r"^(?P<type>:\w+:|\p{Extended_Pictographic}\x{FE0F}?)\s*(?:\((?P<scope>[^\)]+)\))?(?P<breaking>!)?:?\s*(?P<description>.*)$"
+            }
+        }
+    }
+}
+
+/// Cleanup represents how to clean up the commit message before linting.
+///
+/// It follows the `--cleanup` option of `git commit`.
+/// See: https://git-scm.com/docs/git-commit#Documentation/git-commit.txt---cleanupltmodegt
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Cleanup {
+    /// Same as `strip` if the message is read from the file to be edited
+    /// such as `COMMIT_EDITMSG`. Otherwise `whitespace`.
+    #[default]
+    #[serde(rename = "default")]
+    Default,
+
+    /// Same as `whitespace` except that everything from the scissors line is removed.
+    #[serde(rename = "scissors")]
+    Scissors,
+
+    /// Same as `scissors` and also strip the comment lines.
+    #[serde(rename = "strip")]
+    Strip,
+
+    /// Do not change the message at all.
+    #[serde(rename = "verbatim")]
+    Verbatim,
+
+    /// Strip leading and trailing empty lines, trailing whitespaces and
+    /// collapse consecutive empty lines.
+    #[serde(rename = "whitespace")]
+    Whitespace,
+}
+
+impl Config {
+    /// Check whether the message should be ignored.
+    ///
+    /// The default ignores are matched against the subject while the user
+    /// specified ignores are matched against the whole commit message.
+    pub fn is_ignored(&self, message: &Message) -> bool {
+        static DEFAULT_IGNORE_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+            DEFAULT_IGNORES
+                .iter()
+                .map(|pattern| Regex::new(pattern).unwrap())
+                .collect()
+        });
+        static DEFAULT_REVERT_IGNORE_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(DEFAULT_REVERT_IGNORE).unwrap());
+
+        let subject = message.subject.as_deref().unwrap_or_default().trim();
+        if self.default_ignores.unwrap_or(true)
+            && (DEFAULT_IGNORE_REGEXES
+                .iter()
+                .any(|regex| regex.is_match(subject))
+                || (!self.lints_reverts() && DEFAULT_REVERT_IGNORE_REGEX.is_match(subject)))
+        {
+            return true;
+        }
+
+        self.ignores
+            .iter()
+            .any(|pattern| pattern.is_match(&message.raw))
+    }
+
+    /// Check whether the revert commits are linted.
+    /// It is true if the revert header is parsed explicitly or the revert-sha-empty rule is enabled.
+    fn lints_reverts(&self) -> bool {
+        self.parser.revert_header == Some(true)
+            || self
+                .rules
+                .revert_sha_empty
+                .as_ref()
+                .is_some_and(|rule| rule.level() != Level::Ignore)
+    }
+}
+
 impl fmt::Display for Config {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = serde_yaml::to_string(&self).unwrap();
@@ -33,20 +282,43 @@ impl fmt::Display for Config {
 }
 
 /// Load configuration from the specified path.
-pub fn load(path: Option<PathBuf>) -> Result<Config, String> {
+/// The dynamic parts such as the scope sources are resolved relative to `cwd`.
+pub fn load(path: Option<PathBuf>, cwd: &Path) -> Result<Config, String> {
     let config_file = match &path {
         Some(p) => Some(p.clone()),
         None => find_config_file(PathBuf::from(DEFAULT_CONFIG_ROOT)),
     };
 
-    match (config_file, path) {
+    let config = match (config_file, path) {
         // If the file was specified and found, load it.
-        (Some(p), _) => load_config_file(p),
+        (Some(p), _) => load_config_file(p).and_then(validate)?,
         // If the file was not specified and not found, return default config.
-        (None, None) => Ok(Config::default()),
+        (None, None) => Config::default(),
         // If the was explicitly specified but not found, return an error.
-        (None, Some(p)) => Err(format!("Configuration file not found in {}", p.display())),
+        (None, Some(p)) => return Err(format!("Configuration file not found in {}", p.display())),
+    };
+
+    resolve(config, cwd)
+}
+
+/// Validate the loaded configuration.
+/// Note that the regexes are validated here to fail fast before reading commits.
+/// The ignores and the patterns of the rules such as `scope-format` are compiled
+/// when they are deserialized.
+fn validate(config: Config) -> Result<Config, String> {
+    config.parser.header_parser()?;
+    config.parser.reference_parser()?;
+
+    Ok(config)
+}
+
+/// Resolve the dynamic parts of the configuration such as the scope sources.
+fn resolve(mut config: Config, cwd: &Path) -> Result<Config, String> {
+    if let Some(scope) = config.rules.scope.as_mut() {
+        scope.resolve(cwd)?;
     }
+
+    Ok(config)
 }
 
 /// Find configuration file in the specified path.
@@ -125,3 +397,99 @@ fn load_unknown_config_file(path: PathBuf) -> Result<Config, String> {
         path.display()
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ignores() {
+        let config = Config::default();
+
+        for subject in [
+            "Merge branch 'main' into feature",
+            "Merge pull request #1 from KeisukeYamashita/feature",
+            "Revert \"feat(cli): add dummy option\"",
+            "fixup! feat(cli): add dummy option",
+            "squash! feat(cli): add dummy option",
+            "amend! feat(cli): add dummy option",
+            "Initial commit",
+        ] {
+            let message = Message::new(subject.to_string());
+            assert!(config.is_ignored(&message), "{} is not ignored", subject);
+        }
+
+        let message = Message::new("feat(cli): add dummy option".to_string());
+        assert!(!config.is_ignored(&message));
+    }
+
+    #[test]
+    fn test_disabled_default_ignores() {
+        let config = Config {
+            default_ignores: Some(false),
+            ..Default::default()
+        };
+
+        let message = Message::new("fixup! feat(cli): add dummy option".to_string());
+        assert!(!config.is_ignored(&message));
+    }
+
+    #[test]
+    fn test_ignores() {
+        let config = Config {
+            ignores: vec![
+                Pattern::new(r"^WIP").unwrap(),
+                Pattern::new(r"(?m)^\[skip lint\]$").unwrap(),
+            ],
+            ..Default::default()
+        };
+
+        let message = Message::new("WIP add dummy option".to_string());
+        assert!(config.is_ignored(&message));
+
+        let message = Message::new("add dummy option\n\n[skip lint]".to_string());
+        assert!(config.is_ignored(&message));
+
+        let message = Message::new("feat(cli): add dummy option".to_string());
+        assert!(!config.is_ignored(&message));
+    }
+
+    #[test]
+    fn test_invalid_rule_pattern() {
+        let config = serde_yaml::from_str::<Config>("rules:\n  scope-format:\n    format: \"(\"\n");
+        assert!(config.is_err());
+        assert!(config
+            .unwrap_err()
+            .to_string()
+            .contains("regex parse error"));
+    }
+
+    #[test]
+    fn test_invalid_ignores() {
+        let config = serde_yaml::from_str::<Config>("ignores:\n  - \"(\"\n");
+        assert!(config.is_err());
+        assert!(config
+            .unwrap_err()
+            .to_string()
+            .contains("regex parse error"));
+    }
+
+    #[test]
+    fn test_revert_ignores() {
+        let message = Message::new("Revert \"feat(cli): add dummy option\"".to_string());
+        assert!(Config::default().is_ignored(&message));
+
+        for text in [
+            "rules:\n  revert-sha-empty:\n    level: error\n",
+            "parser:\n  revert-header: true\nrules: {}\n",
+        ] {
+            let config = serde_yaml::from_str::<Config>(text).unwrap();
+            assert!(!config.is_ignored(&message), "{} ignores reverts", text);
+        }
+
+        let config =
+            serde_yaml::from_str::<Config>("rules:\n  revert-sha-empty:\n    level: ignore\n")
+                .unwrap();
+        assert!(config.is_ignored(&message));
+    }
+}
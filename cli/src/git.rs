@@ -1,7 +1,11 @@
 use regex::Regex;
 use std::path::PathBuf;
+use std::process::Command;
 use std::sync::LazyLock;
-use std::{collections::HashMap, process::Command};
+
+use crate::config::Cleanup;
+use crate::message::{Footer, Reference, Revert, Span, Spans};
+
 /// ReadCommitMessageOptions represents the options for reading commit messages.
 /// Transparently, it is defined to be similar to the behavior of the git log command.
 #[derive(Clone, Debug)]
@@ -44,8 +48,147 @@ pub fn edit_msg_path(cwd: &str) -> PathBuf {
     }
 }
 
-/// Get commit messages from git.
-pub fn read(options: ReadCommitMessageOptions) -> Vec<String> {
+/// Default comment character of git.
+const DEFAULT_COMMENT_CHAR: &str = "#";
+
+/// Candidates of the comment character when `core.commentChar` is `auto`.
+/// See: https://git-scm.com/docs/git-config#Documentation/git-config.txt-corecommentChar
+const AUTO_COMMENT_CHARS: [&str; 10] = ["#", ";", "@", "!", "$", "%", "^", "&", "|", ":"];
+
+/// Scissors line put by `git commit --verbose` following the comment character.
+/// Everything below this line is the diff of the commit.
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// Get the comment character from `core.commentChar` of the git config.
+///
+/// If it is set to `auto`, the character is guessed from the message because
+/// git selects it from the characters which do not begin any line.
+pub fn comment_char(cwd: &str, message: &str) -> String {
+    let output = Command::new("git")
+        .current_dir(cwd)
+        .arg("config")
+        .arg("--get")
+        .arg("core.commentChar")
+        .output();
+
+    let value = match output {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+        _ => return DEFAULT_COMMENT_CHAR.to_string(),
+    };
+
+    match value.as_str() {
+        "" => DEFAULT_COMMENT_CHAR.to_string(),
+        "auto" => guess_comment_char(message),
+        _ => value,
+    }
+}
+
+/// Guess the comment character selected by `core.commentChar=auto`.
+///
+/// The scissors line is the most reliable clue. Otherwise, git appends the
+/// comment lines at the end of the message, so the last line is used.
+fn guess_comment_char(message: &str) -> String {
+    let scissors = AUTO_COMMENT_CHARS.iter().find(|c| {
+        message
+            .lines()
+            .any(|line| line.trim_end() == format!("{} {}", c, SCISSORS))
+    });
+
+    let last_line = message.lines().rev().find(|line| !line.trim().is_empty());
+    let last = AUTO_COMMENT_CHARS
+        .iter()
+        .find(|c| last_line.is_some_and(|line| line.starts_with(*c)));
+
+    scissors
+        .or(last)
+        .unwrap_or(&DEFAULT_COMMENT_CHAR)
+        .to_string()
+}
+
+/// Clean up the commit message in the same way as `git commit --cleanup=<mode>`.
+///
+/// Note that [`Cleanup::Default`] must be resolved by the caller because it
+/// depends on where the message is read from. It is treated as `whitespace` here.
+/// See: https://git-scm.com/docs/git-commit#Documentation/git-commit.txt---cleanupltmodegt
+pub fn cleanup(message: &str, mode: Cleanup, comment: &str) -> String {
+    if mode == Cleanup::Verbatim {
+        return message.to_string();
+    }
+
+    let scissors = format!("{} {}", comment, SCISSORS);
+    let mut lines: Vec<&str> = Vec::new();
+    let mut empty_lines = 0;
+
+    for line in message.lines() {
+        if matches!(mode, Cleanup::Scissors | Cleanup::Strip) && line.trim_end() == scissors {
+            break;
+        }
+
+        if mode == Cleanup::Strip && line.starts_with(comment) {
+            continue;
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+
+        // Collapse consecutive empty lines and drop the leading ones.
+        if empty_lines > 0 && !lines.is_empty() {
+            lines.push("");
+        }
+        empty_lines = 0;
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// Commit represents the metadata of a single commit read from git.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Commit {
+    /// Sha is the full hash of the commit.
+    pub sha: String,
+
+    /// Author is the person who originally wrote the commit.
+    pub author: Signature,
+
+    /// Committer is the person who last applied the commit.
+    pub committer: Signature,
+
+    /// Parents are the full hashes of the parent commits.
+    pub parents: Vec<String>,
+}
+
+/// Signature represents an identity and a timestamp of a commit.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Signature {
+    /// Name of the person.
+    pub name: String,
+
+    /// Email of the person.
+    pub email: String,
+
+    /// Date in the strict ISO 8601 format.
+    pub date: String,
+}
+
+/// Format passed to `git log` to read a commit.
+///
+/// Each field is delimited by NUL so that it is safe to split even if the
+/// commit message contains arbitrary text. Combined with `-z`, the commits are
+/// also delimited by NUL.
+/// See: https://git-scm.com/docs/git-log#_pretty_formats
+const LOG_FORMAT: &str = "%H%x00%P%x00%an%x00%ae%x00%aI%x00%cn%x00%ce%x00%cI%x00%B";
+
+/// Number of the fields in the [`LOG_FORMAT`].
+const LOG_FIELDS: usize = 9;
+
+/// Get commits and their messages from git.
+pub fn read(options: ReadCommitMessageOptions) -> Vec<(Commit, String)> {
     // Configure revision range following the git spec.
     //
     // See: https://git-scm.com/docs/git-log#Documentation/git-log.txt-ltrevision-rangegt
@@ -62,7 +205,8 @@ pub fn read(options: ReadCommitMessageOptions) -> Vec<String> {
     // See https://git-scm.com/docs/git-log
     let stdout = Command::new("git")
         .arg("log")
-        .arg("--pretty=%B")
+        .arg(format!("--format={}", LOG_FORMAT))
+        .arg("-z")
         .arg("--no-merges")
         .arg("--no-decorate")
         .arg("--reverse")
@@ -74,23 +218,83 @@ pub fn read(options: ReadCommitMessageOptions) -> Vec<String> {
         .stdout;
 
     let stdout = String::from_utf8_lossy(&stdout);
-    extract_commit_messages(&stdout)
+    parse_log(&stdout)
 }
 
-fn extract_commit_messages(input: &str) -> Vec<String> {
-    static COMMIT_DELIMITER: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"(?m)^commit [0-9a-f]{40}$").unwrap());
-    let commits: Vec<&str> = COMMIT_DELIMITER.split(input).collect();
+/// Parse the output of `git log` formatted with [`LOG_FORMAT`].
+fn parse_log(input: &str) -> Vec<(Commit, String)> {
+    let fields: Vec<&str> = input.split('\0').collect();
 
-    let mut messages: Vec<String> = Vec::new();
+    fields
+        .chunks_exact(LOG_FIELDS)
+        .map(|chunk| {
+            let commit = Commit {
+                sha: chunk[0].to_string(),
+                parents: chunk[1].split_whitespace().map(str::to_string).collect(),
+                author: Signature {
+                    name: chunk[2].to_string(),
+                    email: chunk[3].to_string(),
+                    date: chunk[4].to_string(),
+                },
+                committer: Signature {
+                    name: chunk[5].to_string(),
+                    email: chunk[6].to_string(),
+                    date: chunk[7].to_string(),
+                },
+            };
 
-    for commit in commits {
-        let message_lines: Vec<&str> = commit.trim().lines().collect();
-        let message = message_lines.join("\n");
-        messages.push(message);
-    }
+            // Note that the leading whitespaces of the header are kept for the header-trim rule.
+            let message_lines: Vec<&str> = chunk[8].trim_end().lines().collect();
+            (commit, message_lines.join("\n"))
+        })
+        .collect()
+}
+
+/// Get the files changed by the commit relative to the repository root.
+///
+/// See: https://git-scm.com/docs/git-diff-tree
+pub fn changed_files(cwd: &str, sha: &str) -> Vec<String> {
+    let stdout = Command::new("git")
+        .current_dir(cwd)
+        .arg("diff-tree")
+        .arg("--no-commit-id")
+        .arg("--name-only")
+        .arg("-r")
+        .arg("-z")
+        .arg("--root") // Show the files of the root commit as added.
+        .arg(sha)
+        .output()
+        .expect("Failed to execute git diff-tree")
+        .stdout;
+
+    parse_name_list(&String::from_utf8_lossy(&stdout))
+}
+
+/// Get the files staged in the index relative to the repository root.
+/// These are the files changed by the commit being edited.
+///
+/// See: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---cached
+pub fn staged_files(cwd: &str) -> Vec<String> {
+    let stdout = Command::new("git")
+        .current_dir(cwd)
+        .arg("diff")
+        .arg("--cached")
+        .arg("--name-only")
+        .arg("-z")
+        .output()
+        .expect("Failed to execute git diff")
+        .stdout;
+
+    parse_name_list(&String::from_utf8_lossy(&stdout))
+}
 
-    messages
+/// Parse the NUL delimited file names printed with `--name-only -z`.
+fn parse_name_list(input: &str) -> Vec<String> {
+    input
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
 }
 
 /// Parse a commit message and return the subject, body, and footers.
@@ -105,70 +309,506 @@ fn extract_commit_messages(input: &str) -> Vec<String> {
 ///
 /// [optional footer(s)] <-- Footer
 /// ```
-pub fn parse_commit_message(
-    message: &str,
-) -> (String, Option<String>, Option<HashMap<String, String>>) {
-    let lines: Vec<&str> = message.lines().collect();
-    let mut lines_iter = lines.iter();
-
-    let subject = lines_iter.next().unwrap_or(&"").trim().to_string();
-    let mut body = None;
-    let mut footer = None;
-
-    let mut in_body = false;
-    let mut in_footer = false;
-
-    for line in lines_iter {
-        if line.trim().is_empty() {
-            if in_body {
-                in_body = false;
-                in_footer = true;
-            }
-        } else if in_footer {
-            let parts: Vec<&str> = line.splitn(2, ':').map(|part| part.trim()).collect();
-            if parts.len() == 2 {
-                let key = parts[0].to_string();
-                let value = parts[1].to_string();
-                let footer_map = footer.get_or_insert(HashMap::new());
-                footer_map.insert(key, value);
+///
+/// The footers (`Token: value` or `Token #value`) are the trailing paragraphs
+/// of the message as git trailers. Every line of them must be a footer or
+/// a continuation of the previous footer value indented with whitespaces.
+/// See: https://git-scm.com/docs/git-interpret-trailers
+pub fn parse_commit_message(message: &str) -> (String, Option<String>, Vec<Footer>, Spans) {
+    let lines = lines_with_offsets(message);
+    let mut spans = Spans::default();
+
+    let (subject_offset, subject_line) = lines.first().copied().unwrap_or((0, ""));
+    // Note that the subject is not trimmed so that the header-trim rule can see it.
+    let subject = subject_line.to_string();
+    spans.subject = Some(Span::new(
+        message,
+        subject_offset,
+        subject_offset + subject_line.len(),
+    ));
+
+    let rest = lines.get(1..).unwrap_or_default();
+
+    let footer_start = trailing_footer_start(rest)
+        .or_else(|| glued_footer_start(rest))
+        .unwrap_or(rest.len());
+    let (body_lines, footer_lines) = rest.split_at(footer_start);
+
+    // Note that the lines are kept as they are so that the indentation in the body
+    // such as code blocks is not lost.
+    let body = body_lines
+        .iter()
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    let body = if body.is_empty() { None } else { Some(body) };
+
+    if body.is_some() {
+        let blank_lines = rest.iter().take_while(|(_, line)| is_blank(line));
+        spans.body_leading_blank = blank_lines_span(message, blank_lines);
+    }
+    if footer_start < rest.len() {
+        let blank_lines = body_lines
+            .iter()
+            .rev()
+            .take_while(|(_, line)| is_blank(line));
+        spans.footer_leading_blank = blank_lines_span(message, blank_lines);
+    }
+
+    let non_empty_body_lines: Vec<_> = body_lines
+        .iter()
+        .filter(|(_, line)| !is_blank(line))
+        .collect();
+    if let (Some(&&(first_offset, first_line)), Some(&&(last_offset, last_line))) =
+        (non_empty_body_lines.first(), non_empty_body_lines.last())
+    {
+        let start = trimmed_span(message, first_offset, first_line).start;
+        let end = trimmed_span(message, last_offset, last_line).end;
+        spans.body = Some(Span::new(message, start, end));
+    }
+
+    let mut footers: Vec<Footer> = Vec::new();
+    for &(offset, line) in footer_lines {
+        if let Some(mut footer) = parse_footer(line) {
+            footer.span = trimmed_span(message, offset, line);
+            footers.push(footer);
+        } else if let Some(footer) = footers.last_mut() {
+            footer.value.push('\n');
+            footer.value.push_str(line.trim());
+
+            if !line.trim().is_empty() {
+                let end = trimmed_span(message, offset, line).end;
+                footer.span = Span::new(message, footer.span.start, end);
             }
-        } else if !in_body {
-            in_body = true;
-            body = Some(line.trim().to_string());
-        } else if let Some(b) = body.as_mut() {
-            b.push('\n');
-            b.push_str(line.trim());
         }
     }
 
-    (subject, body, footer)
+    for footer in footers.iter_mut() {
+        footer.value = footer.value.trim().to_string();
+    }
+
+    (subject, body, footers, spans)
+}
+
+/// Split the message into lines with the byte offset where each line begins.
+///
+/// Note that the line terminators (`\n` or `\r\n`) are not included in the lines
+/// the same as [`str::lines`].
+fn lines_with_offsets(message: &str) -> Vec<(usize, &str)> {
+    let mut offset = 0;
+    message
+        .split_inclusive('\n')
+        .map(|line| {
+            let begin = offset;
+            offset += line.len();
+            (begin, line.trim_end_matches(['\n', '\r']))
+        })
+        .collect()
+}
+
+/// Check if the line is blank, i.e. empty or only whitespaces.
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Check if the line is the continuation of the footer value indented with whitespaces.
+fn is_continuation(line: &str) -> bool {
+    line.starts_with([' ', '\t'])
+}
+
+/// Check if the line is a footer or the continuation of a footer.
+fn is_footer_line(line: &str) -> bool {
+    is_continuation(line) || parse_footer(line).is_some()
+}
+
+/// Get the start of the footers which are the trailing paragraphs of the message.
+///
+/// Every line of the paragraphs must be a footer or a continuation, and the first
+/// paragraph must begin with a footer. The paragraph of the continuations such as
+/// the second paragraph of a long `BREAKING CHANGE` note continues the previous footer.
+fn trailing_footer_start(lines: &[(usize, &str)]) -> Option<usize> {
+    let mut start = None;
+    let mut end = lines.len();
+    loop {
+        end -= lines[..end]
+            .iter()
+            .rev()
+            .take_while(|(_, l)| is_blank(l))
+            .count();
+        if end == 0 {
+            return start;
+        }
+
+        let begin = lines[..end]
+            .iter()
+            .rposition(|(_, line)| is_blank(line))
+            .map_or(0, |i| i + 1);
+        let paragraph = &lines[begin..end];
+        if !paragraph.iter().all(|(_, line)| is_footer_line(line)) {
+            return start;
+        }
+        if !is_continuation(paragraph[0].1) {
+            start = Some(begin);
+        }
+
+        end = begin;
+    }
+}
+
+/// Get the start of the footers glued to the previous line without a blank line.
+///
+/// They are the trailing lines of the message which are all footers such as
+/// `Refs: #123` right after the body text.
+fn glued_footer_start(lines: &[(usize, &str)]) -> Option<usize> {
+    let end = lines.len() - lines.iter().rev().take_while(|(_, l)| is_blank(l)).count();
+    let count = lines[..end]
+        .iter()
+        .rev()
+        .take_while(|(_, line)| is_footer_line(line))
+        .count();
+
+    // Note that the leading continuations are the body such as a code block.
+    lines[end - count..end]
+        .iter()
+        .position(|(_, line)| !is_continuation(line))
+        .map(|i| end - count + i)
+}
+
+/// Get the span covering the blank lines in any order.
+fn blank_lines_span<'a>(
+    message: &str,
+    lines: impl Iterator<Item = &'a (usize, &'a str)>,
+) -> Option<Span> {
+    let (start, end) = lines.fold(None, |range: Option<(usize, usize)>, &(offset, line)| {
+        let (start, end) = range.unwrap_or((offset, offset + line.len()));
+        Some((start.min(offset), end.max(offset + line.len())))
+    })?;
+
+    Some(Span::new(message, start, end))
+}
+
+/// Get the span of the line beginning at the offset without the surrounding whitespaces.
+fn trimmed_span(message: &str, offset: usize, line: &str) -> Span {
+    let start = offset + (line.len() - line.trim_start().len());
+    let end = offset + line.trim_end().len();
+    Span::new(message, start, end.max(start))
 }
 
-/// Parse a commit message subject and return the type, scope, and description.
+/// Parse a line which starts a footer and return it.
 ///
-/// Note that exclamation mark is not respected as the existing commitlint
-/// does not have any rules for it.
-/// See: https://commitlint.js.org/reference/rules.html
-pub fn parse_subject(subject: &str) -> (Option<String>, Option<String>, Option<String>) {
-    static SUBJECT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(r"^(?P<type>\w+)(?:\((?P<scope>[^\)]+)\))?(?:!)?\:\s?(?P<description>.*)$")
-            .unwrap()
+/// The token is either `BREAKING CHANGE` or words joined with `-`, and it is
+/// followed by either `: ` or ` #` separator.
+/// See: https://www.conventionalcommits.org/en/v1.0.0/#specification
+fn parse_footer(line: &str) -> Option<Footer> {
+    static FOOTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^(?P<token>BREAKING CHANGE|[\w-]+)(?P<separator>: | #)(?P<value>.*)$").unwrap()
     });
-    if let Some(captures) = SUBJECT_REGEX.captures(subject) {
-        let r#type = captures.name("type").map(|m| m.as_str().to_string());
-        let scope = captures.name("scope").map(|m| m.as_str().to_string());
-        let description = captures.name("description").map(|m| m.as_str().to_string());
 
-        return (r#type, scope, description);
+    let captures = FOOTER_REGEX.captures(line)?;
+    Some(Footer {
+        token: captures["token"].to_string(),
+        separator: captures["separator"].to_string(),
+        value: captures["value"].trim().to_string(),
+        ..Default::default()
+    })
+}
+
+/// Default pattern of the subject following the Conventional Commits.
+pub const DEFAULT_HEADER_PATTERN: &str =
+    r"^(?P<type>\w+)(?:\((?P<scope>[^\)]+)\))?(?P<breaking>!)?\:\s?(?P<description>.*)$";
+
+/// Default delimiters of the multiple scopes such as `feat(api,cli): ...`.
+pub const DEFAULT_SCOPE_DELIMITERS: [&str; 3] = [",", "/", "|"];
+
+/// HeaderParser parses a commit message subject with the header pattern.
+///
+/// The parts are captured by the named groups `type`, `scope`, `description`
+/// and `breaking`. If the correspondence is given, the numbered groups are
+/// used instead, and the name at index `i` is assigned to the group `i + 1`
+/// in the same way as `headerCorrespondence` of conventional-commits-parser.
+/// See: https://github.com/conventional-changelog/conventional-changelog/tree/master/packages/conventional-commits-parser#headercorrespondence
+#[derive(Clone, Debug)]
+pub struct HeaderParser {
+    /// Pattern of the subject.
+    pattern: Regex,
+
+    /// Names of the parts corresponding to the numbered groups.
+    correspondence: Vec<String>,
+
+    /// Whether to parse the reverted header instead of the subject of the
+    /// commits generated by `git revert`.
+    revert_header: bool,
+
+    /// Delimiters of the multiple scopes.
+    scope_delimiters: Vec<String>,
+}
+
+impl HeaderParser {
+    /// Create a new HeaderParser.
+    pub fn new(
+        pattern: &str,
+        correspondence: Vec<String>,
+        revert_header: bool,
+    ) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: Regex::new(pattern)?,
+            correspondence,
+            revert_header,
+            scope_delimiters: DEFAULT_SCOPE_DELIMITERS
+                .iter()
+                .map(|delimiter| delimiter.to_string())
+                .collect(),
+        })
+    }
+
+    /// Set the delimiters of the multiple scopes.
+    pub fn with_scope_delimiters(mut self, scope_delimiters: Vec<String>) -> Self {
+        self.scope_delimiters = scope_delimiters;
+        self
+    }
+
+    /// Split the scope into the multiple scopes with the delimiters.
+    ///
+    /// For example, `api,cli` is split into `api` and `cli`. Note that the empty
+    /// scopes such as the trailing one of `api,` are kept for the scope-empty rule.
+    pub fn split_scope(&self, scope: &str) -> Vec<String> {
+        let mut scopes = vec![];
+        let mut rest = scope;
+        while let Some((index, delimiter)) = self
+            .scope_delimiters
+            .iter()
+            .filter(|delimiter| !delimiter.is_empty())
+            .filter_map(|delimiter| rest.find(delimiter.as_str()).map(|i| (i, delimiter)))
+            .min_by_key(|(index, _)| *index)
+        {
+            scopes.push(rest[..index].trim().to_string());
+            rest = &rest[index + delimiter.len()..];
+        }
+        scopes.push(rest.trim().to_string());
+
+        scopes
+    }
+
+    /// Parse a commit message subject and return the type, scope, description
+    /// and whether the exclamation mark is put before the colon.
+    ///
+    /// Note that the returned spans are relative to the subject.
+    /// See: https://www.conventionalcommits.org/en/v1.0.0/#commit-message-with--to-draw-attention-to-breaking-change
+    pub fn parse(
+        &self,
+        subject: &str,
+    ) -> (Option<String>, Option<String>, Option<String>, bool, Spans) {
+        if self.revert_header {
+            if let Some(header) = REVERT_SUBJECT_REGEX
+                .captures(subject)
+                .and_then(|captures| captures.name("header"))
+            {
+                let (r#type, scope, description, breaking, spans) = self.parse(header.as_str());
+
+                // Spans are relative to the reverted header, so shift them.
+                let shift = |span: Option<Span>| {
+                    span.map(|span| {
+                        Span::new(
+                            subject,
+                            header.start() + span.start,
+                            header.start() + span.end,
+                        )
+                    })
+                };
+                let spans = Spans {
+                    breaking: shift(spans.breaking),
+                    r#type: shift(spans.r#type),
+                    scope: shift(spans.scope),
+                    description: shift(spans.description),
+                    ..Default::default()
+                };
+
+                return (r#type, scope, description, breaking, spans);
+            }
+        }
+
+        if let Some(captures) = self.pattern.captures(subject) {
+            let group = |name: &str| {
+                if self.correspondence.is_empty() {
+                    return captures.name(name);
+                }
+
+                self.correspondence
+                    .iter()
+                    .position(|n| n == name)
+                    .and_then(|i| captures.get(i + 1))
+            };
+
+            let r#type = group("type").map(|m| m.as_str().to_string());
+            let scope = group("scope").map(|m| m.as_str().to_string());
+            let description = group("description").map(|m| m.as_str().to_string());
+            let breaking = group("breaking").is_some_and(|m| !m.is_empty());
+
+            let span = |name: &str| group(name).map(|m| Span::new(subject, m.start(), m.end()));
+            let spans = Spans {
+                breaking: span("breaking").filter(|_| breaking),
+                r#type: span("type"),
+                scope: span("scope"),
+                description: span("description"),
+                ..Default::default()
+            };
+
+            return (r#type, scope, description, breaking, spans);
+        }
+        // Fall back to the description.
+        let spans = Spans {
+            description: Some(Span::new(subject, 0, subject.len())),
+            ..Default::default()
+        };
+        (None, None, Some(subject.to_string()), false, spans)
+    }
+}
+
+/// Default implementation of HeaderParser following the Conventional Commits.
+impl Default for HeaderParser {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEADER_PATTERN, vec![], true).unwrap()
     }
-    // Fall back to the description.
-    (None, None, Some(subject.to_string()))
+}
+
+/// Pattern of the GitHub style references such as `#123` or `owner/repo#123`.
+const ISSUE_REFERENCE_PATTERN: &str = r"(?:\b(?P<repository>[\w.-]+/[\w.-]+))?#(?P<issue>\d+)\b";
+
+/// Pattern of the action keywords before the reference such as `Closes #123`.
+/// See: https://docs.github.com/en/issues/tracking-your-work-with-issues/linking-a-pull-request-to-an-issue
+const ACTION_PATTERN: &str = r"(?i)\b(?P<action>close[sd]?|fix(?:e[sd])?|resolve[sd]?):?[ \t]+$";
+
+/// ReferenceParser extracts the issue references from the commit message.
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceParser {
+    /// Patterns of the ticket keys such as `PROJ-\d+`.
+    ticket_patterns: Vec<Regex>,
+}
+
+impl ReferenceParser {
+    /// Create a new ReferenceParser.
+    pub fn new(ticket_patterns: &[String]) -> Result<Self, regex::Error> {
+        Ok(Self {
+            ticket_patterns: ticket_patterns
+                .iter()
+                .map(|pattern| Regex::new(pattern))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Parse the raw commit message and return the references in order of appearance.
+    pub fn parse(&self, message: &str) -> Vec<Reference> {
+        static ISSUE_REFERENCE_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ISSUE_REFERENCE_PATTERN).unwrap());
+        static ACTION_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ACTION_PATTERN).unwrap());
+
+        let mut matches: Vec<(usize, usize, Option<String>, String)> = ISSUE_REFERENCE_REGEX
+            .captures_iter(message)
+            .map(|captures| {
+                let m = captures.get(0).unwrap();
+                (
+                    m.start(),
+                    m.end(),
+                    captures.name("repository").map(|r| r.as_str().to_string()),
+                    captures["issue"].to_string(),
+                )
+            })
+            .collect();
+        for regex in &self.ticket_patterns {
+            matches.extend(
+                regex
+                    .find_iter(message)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| (m.start(), m.end(), None, m.as_str().to_string())),
+            );
+        }
+
+        // Keep the first one of the overlapping references.
+        matches.sort_by_key(|(start, end, ..)| (*start, std::cmp::Reverse(*end)));
+        let mut last_end = 0;
+        matches.retain(|(start, end, ..)| {
+            let overlapped = *start < last_end;
+            last_end = last_end.max(*end);
+            !overlapped
+        });
+
+        matches
+            .into_iter()
+            .map(|(start, end, repository, issue)| Reference {
+                action: ACTION_REGEX
+                    .captures(&message[..start])
+                    .map(|captures| captures["action"].to_string()),
+                issue,
+                raw: message[start..end].to_string(),
+                repository,
+                span: Span::new(message, start, end),
+            })
+            .collect()
+    }
+}
+
+/// Pattern of the subject of the commits generated by `git revert`.
+static REVERT_SUBJECT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^Revert "(?P<header>.*)"$"#).unwrap());
+
+/// Parse the commit generated by `git revert` and return the reverted header
+/// and the SHA written in the `This reverts commit <sha>.` line of the body.
+///
+/// ```ignore
+/// Revert "feat(cli): add dummy option"
+///
+/// This reverts commit 1234567890abcdef1234567890abcdef12345678.
+/// ```
+pub fn parse_revert(subject: &str, body: Option<&str>) -> Option<Revert> {
+    static REVERT_BODY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?m)^This reverts commit (?P<sha>[0-9a-f]{7,40})\b").unwrap()
+    });
+
+    let captures = REVERT_SUBJECT_REGEX.captures(subject)?;
+    let sha = body
+        .and_then(|body| REVERT_BODY_REGEX.captures(body))
+        .map(|captures| captures["sha"].to_string());
+
+    Some(Revert {
+        header: captures["header"].to_string(),
+        sha,
+    })
+}
+
+/// Parse a commit message subject with the default header pattern.
+///
+/// See [`HeaderParser::parse`] for the details.
+pub fn parse_subject(
+    subject: &str,
+) -> (Option<String>, Option<String>, Option<String>, bool, Spans) {
+    static DEFAULT_PARSER: LazyLock<HeaderParser> = LazyLock::new(HeaderParser::default);
+    DEFAULT_PARSER.parse(subject)
+}
+
+/// Check whether the footer token is the breaking change token.
+///
+/// Note that `BREAKING-CHANGE` is synonymous with `BREAKING CHANGE`.
+/// See: https://www.conventionalcommits.org/en/v1.0.0/#specification
+pub fn is_breaking_change_token(token: &str) -> bool {
+    token == "BREAKING CHANGE" || token == "BREAKING-CHANGE"
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_name_list() {
+        assert_eq!(
+            parse_name_list("cli/src/git.rs\0web/docs/a b.md\0"),
+            vec!["cli/src/git.rs".to_string(), "web/docs/a b.md".to_string()]
+        );
+        assert!(parse_name_list("").is_empty());
+    }
+
     #[test]
     fn test_edit_msg_path() {
         let path = edit_msg_path(".");
@@ -176,135 +816,871 @@ mod tests {
     }
 
     #[test]
-    fn test_single_line_parse_commit_message() {
-        let input = "feat(cli): add dummy option";
-        let (subject, body, footer) = parse_commit_message(input);
-        assert_eq!(subject, "feat(cli): add dummy option");
-        assert_eq!(body, None);
-        assert_eq!(footer, None);
+    fn test_cleanup_verbatim() {
+        let input = "\nfeat(cli): add dummy option  \n# comment\n";
+        assert_eq!(cleanup(input, Cleanup::Verbatim, "#"), input);
     }
 
     #[test]
-    fn test_body_parse_commit_message() {
-        let input = "feat(cli): add dummy option
+    fn test_cleanup_whitespace() {
+        let input = "
 
-Hello, there!";
-        let (subject, body, footer) = parse_commit_message(input);
-        assert_eq!(subject, "feat(cli): add dummy option");
-        assert_eq!(body, Some("Hello, there!".to_string()));
-        assert_eq!(footer, None);
-    }
+feat(cli): add dummy option  
 
-    #[test]
-    fn test_footer_parse_commit_message() {
-        let input = "feat(cli): add dummy option
 
-Hello, there!
+Hello, there!   
+# comment
 
-Link: Hello";
-        let (subject, body, footer) = parse_commit_message(input);
+";
+        assert_eq!(
+            cleanup(input, Cleanup::Whitespace, "#"),
+            "feat(cli): add dummy option
 
-        let mut f = HashMap::new();
-        f.insert("Link".to_string(), "Hello".to_string());
-        assert_eq!(subject, "feat(cli): add dummy option");
-        assert_eq!(body, Some("Hello, there!".to_string()));
-        assert!(footer.is_some());
-        assert_eq!(f.get("Link"), Some(&"Hello".to_string()));
+Hello, there!
+# comment"
+        );
     }
 
     #[test]
-    fn test_footer_with_multiline_body_parse_commit_message() {
+    fn test_cleanup_strip() {
         let input = "feat(cli): add dummy option
 
+# Please enter the commit message for your changes.
 Hello, there!
-I'm from Japan!
-
-Link: Hello";
-        let (subject, body, footer) = parse_commit_message(input);
-
-        let mut f = HashMap::new();
-        f.insert("Link".to_string(), "Hello".to_string());
-        assert_eq!(subject, "feat(cli): add dummy option");
+# ------------------------ >8 ------------------------
+diff --git a/cli/src/git.rs b/cli/src/git.rs
++
+";
         assert_eq!(
-            body,
-            Some(
-                "Hello, there!
-I'm from Japan!"
-                    .to_string()
-            )
+            cleanup(input, Cleanup::Strip, "#"),
+            "feat(cli): add dummy option
+
+Hello, there!"
         );
-        assert!(footer.is_some());
-        assert_eq!(f.get("Link"), Some(&"Hello".to_string()));
     }
 
     #[test]
-    fn test_multiple_footers_parse_commit_message() {
+    fn test_cleanup_strip_with_comment_char() {
         let input = "feat(cli): add dummy option
 
-Hello, there!
-
-Link: Hello
-Name: Keke";
-        let (subject, body, footer) = parse_commit_message(input);
-
-        assert_eq!(subject, "feat(cli): add dummy option");
-        assert_eq!(body, Some("Hello, there!".to_string()));
-        assert!(footer.is_some());
+#1 is fixed
+; comment";
         assert_eq!(
-            footer.clone().unwrap().get("Link"),
-            Some(&"Hello".to_string())
+            cleanup(input, Cleanup::Strip, ";"),
+            "feat(cli): add dummy option
+
+#1 is fixed"
         );
-        assert_eq!(footer.unwrap().get("Name"), Some(&"Keke".to_string()));
     }
 
     #[test]
-    fn test_parse_subject_with_scope() {
-        let input = "feat(cli): add dummy option";
+    fn test_cleanup_scissors() {
+        let input = "feat(cli): add dummy option
+
+# Please enter the commit message for your changes.
+# ------------------------ >8 ------------------------
+diff --git a/cli/src/git.rs b/cli/src/git.rs
+";
         assert_eq!(
-            parse_subject(input),
-            (
-                Some("feat".to_string()),
-                Some("cli".to_string()),
-                Some("add dummy option".to_string())
-            )
+            cleanup(input, Cleanup::Scissors, "#"),
+            "feat(cli): add dummy option
+
+# Please enter the commit message for your changes."
         );
     }
 
     #[test]
-    fn test_parse_subject_with_emphasized_type_with_scope() {
-        let input = "feat(cli)!: add dummy option";
+    fn test_guess_comment_char() {
+        assert_eq!(guess_comment_char("feat(cli): add dummy option"), "#");
         assert_eq!(
-            parse_subject(input),
-            (
-                Some("feat".to_string()),
-                Some("cli".to_string()),
-                Some("add dummy option".to_string())
-            )
+            guess_comment_char("feat(cli): add dummy option\n\n; comment\n"),
+            ";"
+        );
+        assert_eq!(
+            guess_comment_char(
+                "feat(cli): add dummy option\n@ ------------------------ >8 ------------------------\n+diff"
+            ),
+            "@"
         );
     }
 
     #[test]
-    fn test_parse_subject_without_scope() {
-        let input = "feat: add dummy option";
+    fn test_parse_log() {
+        let input = [
+            "1111111111111111111111111111111111111111",
+            "",
+            "Keke",
+            "keke@example.com",
+            "2024-01-01T00:00:00+09:00",
+            "Keke",
+            "keke@example.com",
+            "2024-01-01T00:00:00+09:00",
+            "feat(cli): add dummy option\n\nHello, there!\n",
+            "2222222222222222222222222222222222222222",
+            "1111111111111111111111111111111111111111",
+            "Keke",
+            "keke@example.com",
+            "2024-01-02T00:00:00+09:00",
+            "Bot",
+            "bot@example.com",
+            "2024-01-03T00:00:00+09:00",
+            "fix(cli): fix dummy option\n",
+            "",
+        ]
+        .join("\0");
+        let commits = parse_log(&input);
+
+        assert_eq!(commits.len(), 2);
+
+        let (commit, message) = &commits[0];
+        assert_eq!(commit.sha, "1111111111111111111111111111111111111111");
+        assert!(commit.parents.is_empty());
+        assert_eq!(commit.author.name, "Keke");
+        assert_eq!(commit.author.email, "keke@example.com");
+        assert_eq!(message, "feat(cli): add dummy option\n\nHello, there!");
+
+        let (commit, message) = &commits[1];
+        assert_eq!(commit.sha, "2222222222222222222222222222222222222222");
         assert_eq!(
-            parse_subject(input),
-            (
-                Some("feat".to_string()),
-                None,
-                Some("add dummy option".to_string())
-            )
+            commit.parents,
+            vec!["1111111111111111111111111111111111111111".to_string()]
         );
+        assert_eq!(commit.author.date, "2024-01-02T00:00:00+09:00");
+        assert_eq!(commit.committer.name, "Bot");
+        assert_eq!(commit.committer.email, "bot@example.com");
+        assert_eq!(commit.committer.date, "2024-01-03T00:00:00+09:00");
+        assert_eq!(message, "fix(cli): fix dummy option");
     }
 
     #[test]
-    fn test_parse_subject_with_emphasized_type_without_scope() {
-        let input = "feat!: add dummy option";
-        assert_eq!(
-            parse_subject(input),
-            (
-                Some("feat".to_string()),
-                None,
-                Some("add dummy option".to_string())
+    fn test_parse_log_with_leading_whitespaces() {
+        let input = [
+            "1111111111111111111111111111111111111111",
+            "",
+            "Keke",
+            "keke@example.com",
+            "2024-01-01T00:00:00+09:00",
+            "Keke",
+            "keke@example.com",
+            "2024-01-01T00:00:00+09:00",
+            "  feat(cli): add dummy option\n\n",
+            "",
+        ]
+        .join("\0");
+        let commits = parse_log(&input);
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].1, "  feat(cli): add dummy option");
+    }
+
+    #[test]
+    fn test_parse_empty_log() {
+        assert!(parse_log("").is_empty());
+    }
+
+    /// Get the token, separator and value of the footers without the spans.
+    fn footer_parts(footers: &[Footer]) -> Vec<(&str, &str, &str)> {
+        footers
+            .iter()
+            .map(|f| (f.token.as_str(), f.separator.as_str(), f.value.as_str()))
+            .collect()
+    }
+
+    /// Parse the subject without the spans.
+    fn parse_subject_parts(
+        subject: &str,
+    ) -> (Option<String>, Option<String>, Option<String>, bool) {
+        let (r#type, scope, description, breaking, _) = parse_subject(subject);
+        (r#type, scope, description, breaking)
+    }
+
+    #[test]
+    fn test_single_line_parse_commit_message() {
+        let input = "feat(cli): add dummy option";
+        let (subject, body, footers, _) = parse_commit_message(input);
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(body, None);
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_untrimmed_subject_parse_commit_message() {
+        let input = "  feat(cli): add dummy option \n\nbody";
+        let (subject, _, _, spans) = parse_commit_message(input);
+        assert_eq!(subject, "  feat(cli): add dummy option ");
+
+        let span = spans.subject.unwrap();
+        assert_eq!((span.start, span.end), (0, 30));
+    }
+
+    #[test]
+    fn test_body_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!";
+        let (subject, body, footers, _) = parse_commit_message(input);
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_multiple_paragraphs_body_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+
+I'm from Japan!";
+        let (subject, body, footers, _) = parse_commit_message(input);
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(
+            body,
+            Some(
+                "Hello, there!
+
+I'm from Japan!"
+                    .to_string()
+            )
+        );
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_footer_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+
+Link: Hello";
+        let (subject, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert_eq!(footer_parts(&footers), vec![("Link", ": ", "Hello")]);
+    }
+
+    #[test]
+    fn test_footer_with_multiline_body_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+I'm from Japan!
+
+Link: Hello";
+        let (subject, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(
+            body,
+            Some(
+                "Hello, there!
+I'm from Japan!"
+                    .to_string()
+            )
+        );
+        assert_eq!(footer_parts(&footers), vec![("Link", ": ", "Hello")]);
+    }
+
+    #[test]
+    fn test_multiple_footers_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+
+Link: Hello
+Name: Keke";
+        let (subject, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert_eq!(
+            footer_parts(&footers),
+            vec![("Link", ": ", "Hello"), ("Name", ": ", "Keke")]
+        );
+    }
+
+    #[test]
+    fn test_repeated_footers_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Co-authored-by: Keke <keke@example.com>
+Co-authored-by: Bot <bot@example.com>
+Signed-off-by: Keke <keke@example.com>";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(body, None);
+        assert_eq!(
+            footer_parts(&footers),
+            vec![
+                ("Co-authored-by", ": ", "Keke <keke@example.com>"),
+                ("Co-authored-by", ": ", "Bot <bot@example.com>"),
+                ("Signed-off-by", ": ", "Keke <keke@example.com>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hash_separator_footer_parse_commit_message() {
+        let input = "fix(cli): fix dummy option
+
+Fixes #123
+Reviewed-by: Keke";
+        let (_, _, footers, _) = parse_commit_message(input);
+
+        assert_eq!(
+            footer_parts(&footers),
+            vec![("Fixes", " #", "123"), ("Reviewed-by", ": ", "Keke")]
+        );
+    }
+
+    #[test]
+    fn test_multiline_footer_parse_commit_message() {
+        let input = "feat(cli)!: add dummy option
+
+BREAKING CHANGE: dummy option is required
+  and the old option is removed.
+Refs: #123";
+        let (_, _, footers, _) = parse_commit_message(input);
+
+        assert_eq!(
+            footer_parts(&footers),
+            vec![
+                (
+                    "BREAKING CHANGE",
+                    ": ",
+                    "dummy option is required\nand the old option is removed."
+                ),
+                ("Refs", ": ", "#123"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_paragraphs_footer_parse_commit_message() {
+        let input = "feat(cli)!: add dummy option
+
+BREAKING CHANGE: the v1 API is removed.
+
+  Migrate to the v2 API.
+Refs: #123";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(body, None);
+        assert_eq!(
+            footer_parts(&footers),
+            vec![
+                (
+                    "BREAKING CHANGE",
+                    ": ",
+                    "the v1 API is removed.\n\nMigrate to the v2 API."
+                ),
+                ("Refs", ": ", "#123"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_footer_like_body_paragraph_parse_commit_message() {
+        let input = "fix: x
+
+Note: this changes the parser.
+
+Some more body text.";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(
+            body,
+            Some("Note: this changes the parser.\n\nSome more body text.".to_string())
+        );
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_hash_footer_like_body_paragraph_parse_commit_message() {
+        let input = "fix: x
+
+Closes #12 by adjusting the parser.
+It was too greedy.
+
+Refs: #34";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(
+            body,
+            Some("Closes #12 by adjusting the parser.\nIt was too greedy.".to_string())
+        );
+        assert_eq!(footer_parts(&footers), vec![("Refs", ": ", "#34")]);
+    }
+
+    #[test]
+    fn test_unindented_line_is_not_continuation_parse_commit_message() {
+        let input = "fix: x
+
+Refs: #12
+Some more body text.";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(body, Some("Refs: #12\nSome more body text.".to_string()));
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_url_is_not_footer_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+https://example.com";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(body, Some("https://example.com".to_string()));
+        assert!(footers.is_empty());
+    }
+
+    #[test]
+    fn test_breaking_change_footer_parse_commit_message() {
+        let input = "feat(cli)!: add dummy option
+
+Hello, there!
+BREAKING CHANGE: dummy option is required";
+        let (subject, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli)!: add dummy option");
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert_eq!(
+            footer_parts(&footers),
+            vec![("BREAKING CHANGE", ": ", "dummy option is required")]
+        );
+    }
+
+    #[test]
+    fn test_breaking_change_footer_without_body_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+BREAKING-CHANGE: dummy option is required";
+        let (subject, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(subject, "feat(cli): add dummy option");
+        assert_eq!(body, None);
+        assert_eq!(
+            footer_parts(&footers),
+            vec![("BREAKING-CHANGE", ": ", "dummy option is required")]
+        );
+    }
+
+    #[test]
+    fn test_spans_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+  Hello, there!
+I'm from Japan!
+
+Link: Hello
+  World
+Name: Keke";
+        let (_, _, footers, spans) = parse_commit_message(input);
+
+        let subject = spans.subject.unwrap();
+        assert_eq!(
+            &input[subject.start..subject.end],
+            "feat(cli): add dummy option"
+        );
+        assert_eq!((subject.line, subject.column), (1, 1));
+
+        let body = spans.body.unwrap();
+        assert_eq!(
+            &input[body.start..body.end],
+            "Hello, there!
+I'm from Japan!"
+        );
+        assert_eq!((body.line, body.column), (3, 3));
+
+        assert_eq!(
+            &input[footers[0].span.start..footers[0].span.end],
+            "Link: Hello
+  World"
+        );
+        assert_eq!((footers[0].span.line, footers[0].span.column), (6, 1));
+        assert_eq!(
+            &input[footers[1].span.start..footers[1].span.end],
+            "Name: Keke"
+        );
+        assert_eq!((footers[1].span.line, footers[1].span.column), (8, 1));
+    }
+
+    #[test]
+    fn test_keep_duplicated_footers_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Signed-off-by: Alice <alice@example.com>
+Refs: #123
+
+Signed-off-by: Alice <alice@example.com>";
+        let (_, body, footers, _) = parse_commit_message(input);
+
+        assert_eq!(body, None);
+        assert_eq!(
+            footer_parts(&footers),
+            vec![
+                ("Signed-off-by", ": ", "Alice <alice@example.com>"),
+                ("Refs", ": ", "#123"),
+                ("Signed-off-by", ": ", "Alice <alice@example.com>"),
+            ]
+        );
+        assert_eq!(
+            &input[footers[1].span.start..footers[1].span.end],
+            "Refs: #123"
+        );
+    }
+
+    #[test]
+    fn test_keep_body_layout_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Run the following command.
+
+    $ commitlint --dummy";
+        let (_, body, _, _) = parse_commit_message(input);
+
+        assert_eq!(
+            body,
+            Some("Run the following command.\n\n    $ commitlint --dummy".to_string())
+        );
+    }
+
+    #[test]
+    fn test_glued_footer_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+Refs: #123";
+        let (_, body, footers, spans) = parse_commit_message(input);
+
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert_eq!(footer_parts(&footers), vec![("Refs", ": ", "#123")]);
+        assert!(spans.body_leading_blank.is_some());
+        assert_eq!(spans.footer_leading_blank, None);
+    }
+
+    #[test]
+    fn test_leading_blank_spans_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+
+Hello, there!
+
+
+Refs: #123";
+        let (_, _, _, spans) = parse_commit_message(input);
+
+        let body_leading_blank = spans.body_leading_blank.unwrap();
+        assert_eq!((body_leading_blank.line, body_leading_blank.start), (2, 28));
+
+        let footer_leading_blank = spans.footer_leading_blank.unwrap();
+        assert_eq!(footer_leading_blank.line, 4);
+        assert_eq!(
+            &input[footer_leading_blank.start..footer_leading_blank.end],
+            "\n"
+        );
+    }
+
+    #[test]
+    fn test_body_without_leading_blank_parse_commit_message() {
+        let input = "feat(cli): add dummy option
+Hello, there!";
+        let (_, body, _, spans) = parse_commit_message(input);
+
+        assert_eq!(body, Some("Hello, there!".to_string()));
+        assert_eq!(spans.body_leading_blank, None);
+    }
+
+    #[test]
+    fn test_spans_parse_subject() {
+        let input = "feat(cli)!: add dummy option";
+        let (_, _, _, _, spans) = parse_subject(input);
+
+        let r#type = spans.r#type.unwrap();
+        assert_eq!(&input[r#type.start..r#type.end], "feat");
+        assert_eq!(r#type.column, 1);
+
+        let scope = spans.scope.unwrap();
+        assert_eq!(&input[scope.start..scope.end], "cli");
+        assert_eq!(scope.column, 6);
+
+        let description = spans.description.unwrap();
+        assert_eq!(
+            &input[description.start..description.end],
+            "add dummy option"
+        );
+        assert_eq!(description.column, 13);
+    }
+
+    #[test]
+    fn test_header_parser_with_named_groups() {
+        let parser = HeaderParser::new(
+            r"^\[(?P<ticket>[A-Z]+-\d+)\] (?P<type>[\w-]+)(?:\((?P<scope>[^\)]+)\))?: (?P<description>.*)$",
+            vec![],
+            true,
+        )
+        .unwrap();
+        let (r#type, scope, description, breaking, spans) =
+            parser.parse("[ABC-12] build-deps(cli): bump clap");
+
+        assert_eq!(r#type, Some("build-deps".to_string()));
+        assert_eq!(scope, Some("cli".to_string()));
+        assert_eq!(description, Some("bump clap".to_string()));
+        assert!(!breaking);
+        assert_eq!(spans.r#type.unwrap().column, 10);
+    }
+
+    #[test]
+    fn test_header_parser_with_correspondence() {
+        let parser = HeaderParser::new(
+            r"^(\w*)(?:\((.*)\))?(!)?: (.*)$",
+            vec![
+                "type".to_string(),
+                "scope".to_string(),
+                "breaking".to_string(),
+                "description".to_string(),
+            ],
+            true,
+        )
+        .unwrap();
+
+        let (r#type, scope, description, breaking, spans) =
+            parser.parse("feat(cli)!: add dummy option");
+
+        assert_eq!(r#type, Some("feat".to_string()));
+        assert_eq!(scope, Some("cli".to_string()));
+        assert_eq!(description, Some("add dummy option".to_string()));
+        assert!(breaking);
+        assert_eq!(spans.breaking.unwrap().column, 10);
+    }
+
+    #[test]
+    fn test_header_parser_with_presets() {
+        use crate::config::Preset;
+
+        let parse = |preset: Preset, subject: &str| {
+            let parser = HeaderParser::new(preset.header_pattern(), vec![], true).unwrap();
+            let (r#type, scope, description, _, _) = parser.parse(subject);
+            (r#type, scope, description)
+        };
+
+        assert_eq!(
+            parse(Preset::Angular, "feat(cli): add dummy option"),
+            (
+                Some("feat".to_string()),
+                Some("cli".to_string()),
+                Some("add dummy option".to_string())
+            )
+        );
+        assert_eq!(
+            parse(Preset::Eslint, "Fix: semi rule (fixes #1234)"),
+            (
+                Some("Fix".to_string()),
+                None,
+                Some("semi rule (fixes #1234)".to_string())
+            )
+        );
+        assert_eq!(
+            parse(Preset::Gitmoji, ":sparkles: add dummy option"),
+            (
+                Some(":sparkles:".to_string()),
+                None,
+                Some("add dummy option".to_string())
+            )
+        );
+        assert_eq!(
+            parse(Preset::Gitmoji, "✨ (cli): add dummy option"),
+            (
+                Some("✨".to_string()),
+                Some("cli".to_string()),
+                Some("add dummy option".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_scope() {
+        let parser = HeaderParser::default();
+
+        assert_eq!(parser.split_scope("cli"), vec!["cli"]);
+        assert_eq!(
+            parser.split_scope("api, cli|web/db"),
+            vec!["api", "cli", "web", "db"]
+        );
+        assert_eq!(parser.split_scope("api,"), vec!["api", ""]);
+    }
+
+    #[test]
+    fn test_split_scope_with_delimiters() {
+        let parser = HeaderParser::default().with_scope_delimiters(vec![" & ".to_string()]);
+
+        assert_eq!(
+            parser.split_scope("api & api/users"),
+            vec!["api", "api/users"]
+        );
+    }
+
+    #[test]
+    fn test_parse_revert() {
+        let revert = parse_revert(
+            "Revert \"feat(cli): add dummy option\"",
+            Some("This reverts commit 1234567890abcdef1234567890abcdef12345678."),
+        );
+
+        assert_eq!(
+            revert,
+            Some(Revert {
+                header: "feat(cli): add dummy option".to_string(),
+                sha: Some("1234567890abcdef1234567890abcdef12345678".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_revert_without_sha() {
+        let revert = parse_revert("Revert \"feat(cli): add dummy option\"", None);
+
+        assert_eq!(
+            revert,
+            Some(Revert {
+                header: "feat(cli): add dummy option".to_string(),
+                sha: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_not_revert() {
+        assert_eq!(parse_revert("feat(cli): add dummy option", None), None);
+    }
+
+    #[test]
+    fn test_header_parser_with_revert_header() {
+        let subject = "Revert \"feat(cli): add dummy option\"";
+        let (r#type, scope, description, _, spans) = parse_subject(subject);
+
+        assert_eq!(r#type, Some("feat".to_string()));
+        assert_eq!(scope, Some("cli".to_string()));
+        assert_eq!(description, Some("add dummy option".to_string()));
+
+        let scope = spans.scope.unwrap();
+        assert_eq!(&subject[scope.start..scope.end], "cli");
+    }
+
+    #[test]
+    fn test_header_parser_without_revert_header() {
+        let parser = HeaderParser::new(DEFAULT_HEADER_PATTERN, vec![], false).unwrap();
+        let (r#type, _, description, _, _) = parser.parse("Revert \"feat(cli): add dummy option\"");
+
+        assert_eq!(r#type, None);
+        assert_eq!(
+            description,
+            Some("Revert \"feat(cli): add dummy option\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_references() {
+        let parser = ReferenceParser::default();
+        let references = parser.parse(
+            "fix(cli): handle empty input (#12)\n\nSee owner/repo#34.\n\nCloses #56\nFixes: #78",
+        );
+
+        assert_eq!(
+            references
+                .iter()
+                .map(|r| (
+                    r.action.as_deref(),
+                    r.repository.as_deref(),
+                    r.issue.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (None, None, "12"),
+                (None, Some("owner/repo"), "34"),
+                (Some("Closes"), None, "56"),
+                (Some("Fixes"), None, "78"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_references_with_ticket_patterns() {
+        let parser = ReferenceParser::new(&[r"\bPROJ-\d+\b".to_string()]).unwrap();
+        let message = "feat(cli): add new flag\n\nResolves PROJ-123";
+        let references = parser.parse(message);
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].action, Some("Resolves".to_string()));
+        assert_eq!(references[0].issue, "PROJ-123");
+        assert_eq!(references[0].raw, "PROJ-123");
+
+        let span = references[0].span;
+        assert_eq!(&message[span.start..span.end], "PROJ-123");
+        assert_eq!((span.line, span.column), (3, 10));
+    }
+
+    #[test]
+    fn test_parse_references_without_references() {
+        let parser = ReferenceParser::default();
+
+        assert!(parser
+            .parse("feat(cli): add new flag\n\nSee https://example.com/#section")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_subject_with_scope() {
+        let input = "feat(cli): add dummy option";
+        assert_eq!(
+            parse_subject_parts(input),
+            (
+                Some("feat".to_string()),
+                Some("cli".to_string()),
+                Some("add dummy option".to_string()),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_subject_with_emphasized_type_with_scope() {
+        let input = "feat(cli)!: add dummy option";
+        assert_eq!(
+            parse_subject_parts(input),
+            (
+                Some("feat".to_string()),
+                Some("cli".to_string()),
+                Some("add dummy option".to_string()),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_subject_without_scope() {
+        let input = "feat: add dummy option";
+        assert_eq!(
+            parse_subject_parts(input),
+            (
+                Some("feat".to_string()),
+                None,
+                Some("add dummy option".to_string()),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_subject_with_emphasized_type_without_scope() {
+        let input = "feat!: add dummy option";
+        assert_eq!(
+            parse_subject_parts(input),
+            (
+                Some("feat".to_string()),
+                None,
+                Some("add dummy option".to_string()),
+                true
             )
         );
     }
@@ -313,11 +1689,12 @@ Name: Keke";
     fn test_parse_subject_with_empty_description() {
         let input = "feat(cli): ";
         assert_eq!(
-            parse_subject(input),
+            parse_subject_parts(input),
             (
                 Some("feat".to_string()),
                 Some("cli".to_string()),
-                Some("".to_string())
+                Some("".to_string()),
+                false
             )
         );
     }
@@ -326,11 +1703,12 @@ Name: Keke";
     fn test_parse_subject_with_empty_scope() {
         let input = "feat: add dummy commit";
         assert_eq!(
-            parse_subject(input),
+            parse_subject_parts(input),
             (
                 Some("feat".to_string()),
                 None,
-                Some("add dummy commit".to_string())
+                Some("add dummy commit".to_string()),
+                false
             )
         );
     }
@@ -338,12 +1716,18 @@ Name: Keke";
     #[test]
     fn test_parse_subject_without_message() {
         let input = "";
-        assert_eq!(parse_subject(input), (None, None, Some("".to_string())));
+        assert_eq!(
+            parse_subject_parts(input),
+            (None, None, Some("".to_string()), false)
+        );
     }
 
     #[test]
     fn test_parse_subject_with_error_message() {
         let input = "test";
-        assert_eq!(parse_subject(input), (None, None, Some("test".to_string())));
+        assert_eq!(
+            parse_subject_parts(input),
+            (None, None, Some("test".to_string()), false)
+        );
     }
 }
@@ -1,5 +1,7 @@
+pub mod case;
 pub mod config;
 pub mod git;
 pub mod message;
 pub mod result;
 pub mod rule;
+pub mod workspace;
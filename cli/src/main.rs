@@ -1,20 +1,15 @@
 mod args;
-mod config;
-mod git;
-mod message;
-mod result;
-mod rule;
 
 use args::Args;
 use clap::Parser;
-use message::validate;
+use commitlint_rs::{config, message::validate, rule};
 
-use std::process::exit;
+use std::{path::Path, process::exit};
 
 fn main() {
     let args = Args::parse();
 
-    let config = match config::load(args.config.clone()) {
+    let config = match config::load(args.config.clone(), Path::new(&args.cwd)) {
         Ok(c) => c,
         Err(err) => {
             eprintln!("Failed to load config: {}", err);
@@ -26,7 +21,7 @@ fn main() {
         println!("{}", config);
     }
 
-    let messages = match args.read() {
+    let messages = match args.read(&config) {
         Ok(messages) => messages,
         Err(err) => {
             eprintln!("Failed to read commit messages: {}", err);
@@ -36,25 +31,41 @@ fn main() {
 
     let results = messages
         .iter()
-        .map(|message| validate(message, &config))
+        .map(|message| (message, validate(message, &config)))
         .collect::<Vec<_>>();
 
     let mut has_error: bool = false;
-    for result in &results {
+    for (message, result) in &results {
         if let Err(err) = result {
             eprintln!("{}", err);
         }
 
         if let Ok(h) = result {
+            if h.skipped {
+                let subject = message.subject.as_deref().unwrap_or_default();
+                match &message.commit {
+                    Some(commit) => println!("skipped commit {}: {}", commit.sha, subject),
+                    None => println!("skipped: {}", subject),
+                }
+            }
+
             if !h.violations.is_empty() {
+                // Tell which commit the violations belong to when it is read from git.
+                if let Some(commit) = &message.commit {
+                    eprintln!(
+                        "commit {} ({} <{}>)",
+                        commit.sha, commit.author.name, commit.author.email
+                    );
+                }
+
                 for violation in &h.violations {
                     match violation.level {
                         rule::Level::Error => {
-                            eprintln!("{}", violation.message);
+                            eprintln!("{}\n", violation.render(&message.raw));
                             has_error = true
                         }
                         rule::Level::Warning => {
-                            println!("{}", violation.message);
+                            println!("{}\n", violation.render(&message.raw));
                         }
                         _ => {}
                     }
@@ -1,9 +1,12 @@
 use crate::{
     config::Config,
-    git::{parse_commit_message, parse_subject},
+    git::{
+        is_breaking_change_token, parse_commit_message, parse_revert, Commit, HeaderParser,
+        ReferenceParser,
+    },
     result::Result as LintResult,
 };
-use std::{collections::HashMap, fmt::Error};
+use std::fmt::Error;
 
 /// Message represents a single commit message.
 ///
@@ -16,25 +19,50 @@ use std::{collections::HashMap, fmt::Error};
 /// [optional footer(s)]
 /// ```
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Message {
     /// Body part of the commit message.
     pub body: Option<String>,
 
+    /// Breaking represents whether the exclamation mark is put before the colon in the subject.
+    pub breaking: bool,
+
+    /// Breaking changes are the notes of the `BREAKING CHANGE` footers.
+    pub breaking_changes: Vec<String>,
+
+    /// Changed files are the paths changed by the commit relative to the repository root.
+    /// It is only available when a rule needs them and the message is read from git
+    /// or the staged index.
+    pub changed_files: Option<Vec<String>>,
+
+    /// Commit that the message was read from.
+    /// It is only available when the message is read from git.
+    pub commit: Option<Commit>,
+
     /// Description part of the commit message.
     pub description: Option<String>,
-    /// Footers part of the commit message.
-    pub footers: Option<HashMap<String, String>>,
+    /// Footers part of the commit message in the order of appearance.
+    pub footers: Vec<Footer>,
 
-    #[allow(dead_code)]
     /// Raw commit message (or any input from stdin) including the body and footers.
     pub raw: String,
 
+    /// References represents the issue references such as `#123` or `PROJ-123`
+    /// in the subject, body and footers.
+    pub references: Vec<Reference>,
+
+    /// Revert represents the reverted commit if it is generated by `git revert`.
+    pub revert: Option<Revert>,
+
     /// Type part of the commit message.
     pub r#type: Option<String>,
 
-    /// Scope part of the commit message.
-    pub scope: Option<String>,
+    /// Scopes of the commit message split by the delimiters.
+    /// For example, `feat(api,cli): ...` has the scopes `api` and `cli`.
+    pub scope: Vec<String>,
+
+    /// Spans of the parts in the raw commit message.
+    pub spans: Spans,
 
     /// Subject part of the commit message.
     pub subject: Option<String>,
@@ -42,24 +70,183 @@ pub struct Message {
 
 /// Message represents a commit message.
 impl Message {
-    /// Create a new Message.
+    /// Create a new Message with the default parsers.
     pub fn new(raw: String) -> Self {
-        let (subject, body, footers) = parse_commit_message(&raw);
-        let (r#type, scope, description) = parse_subject(&subject);
+        Self::with_parser(raw, &HeaderParser::default(), &ReferenceParser::default())
+    }
+
+    /// Create a new Message with the header parser and the reference parser.
+    pub fn with_parser(
+        raw: String,
+        parser: &HeaderParser,
+        reference_parser: &ReferenceParser,
+    ) -> Self {
+        let (subject, body, footers, mut spans) = parse_commit_message(&raw);
+        let header = subject.trim();
+        let (r#type, scope, description, breaking, subject_spans) = parser.parse(header);
+
+        // Spans of the subject parts are relative to the trimmed subject, so shift them.
+        let offset = spans.subject.map_or(0, |span| span.start)
+            + (subject.len() - subject.trim_start().len());
+        let shift = |span: Option<Span>| {
+            span.map(|span| Span::new(&raw, offset + span.start, offset + span.end))
+        };
+        spans.breaking = shift(subject_spans.breaking);
+        spans.r#type = shift(subject_spans.r#type);
+        spans.scope = shift(subject_spans.scope);
+        spans.description = shift(subject_spans.description);
+
+        let revert = parse_revert(header, body.as_deref());
+        let references = reference_parser.parse(&raw);
+
+        let breaking_changes = footers
+            .iter()
+            .filter(|footer| is_breaking_change_token(&footer.token))
+            .map(|footer| footer.value.clone())
+            .collect();
         Self {
             body,
+            breaking,
+            breaking_changes,
+            changed_files: None,
+            commit: None,
             description,
             footers,
             raw,
+            references,
+            revert,
             r#type,
-            scope,
+            scope: scope
+                .map(|scope| parser.split_scope(&scope))
+                .unwrap_or_default(),
+            spans,
             subject: Some(subject),
         }
     }
 }
 
+/// Footer represents a single footer of the commit message.
+///
+/// Note that the same token can appear multiple times such as `Co-authored-by`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Footer {
+    /// Token of the footer such as `Signed-off-by` or `BREAKING CHANGE`.
+    pub token: String,
+
+    /// Separator between the token and the value, either `: ` or ` #`.
+    pub separator: String,
+
+    /// Value of the footer. It may span multiple lines.
+    pub value: String,
+
+    /// Span of the whole footer in the raw commit message.
+    pub span: Span,
+}
+
+/// Reference represents an issue reference such as `#123`, `owner/repo#123` or `PROJ-123`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reference {
+    /// Action keyword before the reference such as `Closes` or `Fixes`.
+    pub action: Option<String>,
+
+    /// Issue number or ticket key such as `123` or `PROJ-123`.
+    pub issue: String,
+
+    /// Reference as written in the commit message such as `owner/repo#123`.
+    pub raw: String,
+
+    /// Repository of the reference such as `owner/repo`.
+    pub repository: Option<String>,
+
+    /// Span of the reference in the raw commit message.
+    pub span: Span,
+}
+
+/// Revert represents the commit reverted by `git revert`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Revert {
+    /// Header of the reverted commit.
+    pub header: String,
+
+    /// SHA of the reverted commit written in the `This reverts commit <sha>.` line.
+    pub sha: Option<String>,
+}
+
+/// Span represents the location of a part in the raw commit message.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Span {
+    /// Start is the byte offset where the part begins.
+    pub start: usize,
+
+    /// End is the byte offset where the part ends (exclusive).
+    pub end: usize,
+
+    /// Line is the line number (1-based) where the part begins.
+    pub line: usize,
+
+    /// Column is the column in characters (1-based) where the part begins.
+    pub column: usize,
+}
+
+impl Span {
+    /// Create a new Span from the byte offsets in the source.
+    pub fn new(source: &str, start: usize, end: usize) -> Self {
+        let before = &source[..start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            start,
+            end,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Spans represents the locations of the parts in the raw commit message.
+///
+/// Note that the spans of the footers are stored in each [`Footer`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Spans {
+    /// Span of the body part.
+    pub body: Option<Span>,
+
+    /// Span of the exclamation mark before the colon in the subject.
+    pub breaking: Option<Span>,
+
+    /// Span of the blank lines between the subject and the body.
+    /// It is None if the body is glued to the subject or there is no body.
+    pub body_leading_blank: Option<Span>,
+
+    /// Span of the description part.
+    pub description: Option<Span>,
+
+    /// Span of the blank lines before the footers.
+    /// It is None if the footers are glued to the previous line or there are no footers.
+    pub footer_leading_blank: Option<Span>,
+
+    /// Span of the scope part.
+    pub scope: Option<Span>,
+
+    /// Span of the subject part.
+    pub subject: Option<Span>,
+
+    /// Span of the type part.
+    pub r#type: Option<Span>,
+}
+
 /// validate the raw commit message.
 pub fn validate(msg: &Message, config: &Config) -> Result<LintResult, Error> {
+    if config.is_ignored(msg) {
+        return Ok(LintResult {
+            skipped: true,
+            violations: vec![],
+        });
+    }
+
     let violations = config.rules.validate(msg);
-    Ok(LintResult { violations })
+    Ok(LintResult {
+        skipped: false,
+        violations,
+    })
 }
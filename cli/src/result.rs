@@ -1,8 +1,11 @@
-use crate::rule::Level;
+use crate::{message::Span, rule::Level};
 
 /// Result of the check.
 #[derive(Clone, Debug)]
 pub struct Result {
+    /// Skipped represents whether the message is ignored by the ignore patterns.
+    pub skipped: bool,
+
     /// List of violations to be printed.
     /// If it is empty, then there is no violation.
     pub violations: Vec<Violation>,
@@ -16,4 +19,129 @@ pub struct Violation {
 
     /// Message of the violation.
     pub message: String,
+
+    /// Span of the part that violates the rule.
+    /// It is `None` if the part does not exist in the commit message.
+    pub span: Option<Span>,
+}
+
+impl Violation {
+    /// Render the violation with an annotated snippet of the source like rustc.
+    ///
+    /// ```code
+    /// error: scope cli is not allowed. Only ["api", "web"] are allowed
+    ///  --> 1:6
+    ///   |
+    /// 1 | feat(cli): add new flag
+    ///   |      ^^^
+    /// ```
+    ///
+    /// Note that only the first line is annotated if the span is multi-line.
+    pub fn render(&self, source: &str) -> String {
+        let header = format!("{}: {}", self.level, self.message);
+
+        let span = match self.span {
+            Some(span) if span.start <= source.len() => span,
+            _ => return header,
+        };
+
+        let line_start = source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[span.start..]
+            .find('\n')
+            .map_or(source.len(), |i| span.start + i);
+        let line = source[line_start..line_end].trim_end_matches('\r');
+
+        let end = span.end.clamp(span.start, line_start + line.len());
+        let width = source[span.start..end].chars().count().max(1);
+
+        let number = span.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        format!(
+            "{header}\n{gutter}--> {}:{}\n{gutter} |\n{number} | {line}\n{gutter} | {}{}",
+            span.line,
+            span.column,
+            " ".repeat(span.column - 1),
+            "^".repeat(width),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_without_span() {
+        let violation = Violation {
+            level: Level::Error,
+            message: "body is empty".to_string(),
+            span: None,
+        };
+
+        assert_eq!(
+            violation.render("feat(cli): add new flag"),
+            "error: body is empty"
+        );
+    }
+
+    #[test]
+    fn test_render_with_span() {
+        let source = "feat(cli): add new flag";
+        let violation = Violation {
+            level: Level::Warning,
+            message: "scope cli is not allowed".to_string(),
+            span: Some(Span::new(source, 5, 8)),
+        };
+
+        assert_eq!(
+            violation.render(source),
+            "warning: scope cli is not allowed
+ --> 1:6
+  |
+1 | feat(cli): add new flag
+  |      ^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_with_multiline_span() {
+        let source = "feat(cli): add new flag
+
+Hello, there!
+I'm from Japan!";
+        let violation = Violation {
+            level: Level::Error,
+            message: "body is longer than 10 characters".to_string(),
+            span: Some(Span::new(source, 25, source.len())),
+        };
+
+        assert_eq!(
+            violation.render(source),
+            "error: body is longer than 10 characters
+ --> 3:1
+  |
+3 | Hello, there!
+  | ^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_with_empty_span() {
+        let source = "feat(cli): ";
+        let violation = Violation {
+            level: Level::Error,
+            message: "description is empty".to_string(),
+            span: Some(Span::new(source, 11, 11)),
+        };
+
+        assert_eq!(
+            violation.render(source),
+            "error: description is empty
+ --> 1:12
+  |
+1 | feat(cli): 
+  |            ^"
+        );
+    }
 }
@@ -1,45 +1,127 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
-use crate::{message::Message, result::Violation};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+};
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use self::{
-    body_empty::BodyEmpty, body_max_length::BodyMaxLength, description_empty::DescriptionEmpty,
+    body_case::BodyCase, body_empty::BodyEmpty, body_leading_blank::BodyLeadingBlank,
+    body_max_length::BodyMaxLength, body_max_line_length::BodyMaxLineLength,
+    body_min_length::BodyMinLength, breaking_change_consistency::BreakingChangeConsistency,
+    breaking_change_footer_required::BreakingChangeFooterRequired,
+    description_case::DescriptionCase, description_empty::DescriptionEmpty,
     description_format::DescriptionFormat, description_max_length::DescriptionMaxLength,
-    footers_empty::FootersEmpty, r#type::Type, scope::Scope, scope_empty::ScopeEmpty,
-    scope_format::ScopeFormat, scope_max_length::ScopeMaxLength, subject_empty::SubjectEmpty,
-    type_empty::TypeEmpty, type_format::TypeFormat, type_max_length::TypeMaxLength,
+    description_min_length::DescriptionMinLength, footer_blank_line::FooterBlankLine,
+    footer_duplicate::FooterDuplicate, footer_leading_blank::FooterLeadingBlank,
+    footer_max_line_length::FooterMaxLineLength, footer_min_length::FooterMinLength,
+    footer_order::FooterOrder, footer_required::FooterRequired, footer_tokens::FooterTokens,
+    footer_value_format::FooterValueFormat, footers_empty::FootersEmpty, header_case::HeaderCase,
+    header_full_stop::HeaderFullStop, header_max_length::HeaderMaxLength,
+    header_min_length::HeaderMinLength, header_trim::HeaderTrim, r#type::Type,
+    references_empty::ReferencesEmpty, references_pattern::ReferencesPattern,
+    revert_sha_empty::RevertShaEmpty, scope::Scope, scope_case::ScopeCase, scope_empty::ScopeEmpty,
+    scope_format::ScopeFormat, scope_max_length::ScopeMaxLength, scope_min_length::ScopeMinLength,
+    scope_path::ScopePath, signed_off_by::SignedOffBy, subject_empty::SubjectEmpty,
+    subject_exclamation_mark::SubjectExclamationMark, type_case::TypeCase, type_empty::TypeEmpty,
+    type_format::TypeFormat, type_max_length::TypeMaxLength, type_min_length::TypeMinLength,
+    type_path::TypePath,
 };
 
+pub mod body_case;
 pub mod body_empty;
+pub mod body_leading_blank;
 pub mod body_max_length;
+pub mod body_max_line_length;
+pub mod body_min_length;
+pub mod breaking_change_consistency;
+pub mod breaking_change_footer_required;
+pub mod description_case;
 pub mod description_empty;
 pub mod description_format;
 pub mod description_max_length;
+pub mod description_min_length;
+pub mod footer_blank_line;
+pub mod footer_duplicate;
+pub mod footer_leading_blank;
+pub mod footer_max_line_length;
+pub mod footer_min_length;
+pub mod footer_order;
+pub mod footer_required;
+pub mod footer_tokens;
+pub mod footer_value_format;
 pub mod footers_empty;
+pub mod header_case;
+pub mod header_full_stop;
+pub mod header_max_length;
+pub mod header_min_length;
+pub mod header_trim;
+pub mod references_empty;
+pub mod references_pattern;
+pub mod revert_sha_empty;
 pub mod scope;
+pub mod scope_case;
 pub mod scope_empty;
 pub mod scope_format;
 pub mod scope_max_length;
+pub mod scope_min_length;
+pub mod scope_path;
+pub mod signed_off_by;
 pub mod subject_empty;
+pub mod subject_exclamation_mark;
 pub mod r#type;
+pub mod type_case;
 pub mod type_empty;
 pub mod type_format;
 pub mod type_max_length;
+pub mod type_min_length;
+pub mod type_path;
 
 /// Rules represents the rules of commitlint.
 /// See: https://commitlint.js.org/reference/rules.html
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Rules {
+    #[serde(rename = "body-case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_case: Option<BodyCase>,
+
     #[serde(rename = "body-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body_empty: Option<BodyEmpty>,
 
+    #[serde(rename = "body-leading-blank")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_leading_blank: Option<BodyLeadingBlank>,
+
     #[serde(rename = "body-max-length")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body_max_length: Option<BodyMaxLength>,
 
+    #[serde(rename = "body-max-line-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_max_line_length: Option<BodyMaxLineLength>,
+
+    #[serde(rename = "body-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_min_length: Option<BodyMinLength>,
+
+    #[serde(rename = "breaking-change-consistency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breaking_change_consistency: Option<BreakingChangeConsistency>,
+
+    #[serde(rename = "breaking-change-footer-required")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breaking_change_footer_required: Option<BreakingChangeFooterRequired>,
+
+    #[serde(rename = "description-case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_case: Option<DescriptionCase>,
+
     #[serde(rename = "description-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_empty: Option<DescriptionEmpty>,
@@ -52,14 +134,90 @@ pub struct Rules {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_max_length: Option<DescriptionMaxLength>,
 
+    #[serde(rename = "description-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_min_length: Option<DescriptionMinLength>,
+
+    #[serde(rename = "footer-blank-line")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_blank_line: Option<FooterBlankLine>,
+
+    #[serde(rename = "footer-duplicate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_duplicate: Option<FooterDuplicate>,
+
+    #[serde(rename = "footer-leading-blank")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_leading_blank: Option<FooterLeadingBlank>,
+
+    #[serde(rename = "footer-max-line-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_max_line_length: Option<FooterMaxLineLength>,
+
+    #[serde(rename = "footer-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_min_length: Option<FooterMinLength>,
+
+    #[serde(rename = "footer-order")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_order: Option<FooterOrder>,
+
+    #[serde(rename = "footer-required")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_required: Option<FooterRequired>,
+
+    #[serde(rename = "footer-tokens")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_tokens: Option<FooterTokens>,
+
+    #[serde(rename = "footer-value-format")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_value_format: Option<FooterValueFormat>,
+
     #[serde(rename = "footers-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub footers_empty: Option<FootersEmpty>,
 
+    #[serde(rename = "header-case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_case: Option<HeaderCase>,
+
+    #[serde(rename = "header-full-stop")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_full_stop: Option<HeaderFullStop>,
+
+    #[serde(rename = "header-max-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_max_length: Option<HeaderMaxLength>,
+
+    #[serde(rename = "header-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_min_length: Option<HeaderMinLength>,
+
+    #[serde(rename = "header-trim")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_trim: Option<HeaderTrim>,
+
+    #[serde(rename = "references-empty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references_empty: Option<ReferencesEmpty>,
+
+    #[serde(rename = "references-pattern")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references_pattern: Option<ReferencesPattern>,
+
+    #[serde(rename = "revert-sha-empty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_sha_empty: Option<RevertShaEmpty>,
+
     #[serde(rename = "scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<Scope>,
 
+    #[serde(rename = "scope-case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_case: Option<ScopeCase>,
+
     #[serde(rename = "scope-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope_empty: Option<ScopeEmpty>,
@@ -72,14 +230,34 @@ pub struct Rules {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope_max_length: Option<ScopeMaxLength>,
 
+    #[serde(rename = "scope-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_min_length: Option<ScopeMinLength>,
+
+    #[serde(rename = "scope-path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_path: Option<ScopePath>,
+
+    #[serde(rename = "signed-off-by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signed_off_by: Option<SignedOffBy>,
+
     #[serde(rename = "subject-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject_empty: Option<SubjectEmpty>,
 
+    #[serde(rename = "subject-exclamation-mark")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_exclamation_mark: Option<SubjectExclamationMark>,
+
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<Type>,
 
+    #[serde(rename = "type-case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_case: Option<TypeCase>,
+
     #[serde(rename = "type-empty")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_empty: Option<TypeEmpty>,
@@ -91,93 +269,311 @@ pub struct Rules {
     #[serde(rename = "type-max-length")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_max_length: Option<TypeMaxLength>,
+
+    #[serde(rename = "type-min-length")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_min_length: Option<TypeMinLength>,
+
+    #[serde(rename = "type-path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_path: Option<TypePath>,
 }
 
 /// Rule is a collection of rules.
 impl Rules {
+    /// Check if any of the rules needs the files changed by the commit.
+    /// Note that reading them runs git for each commit.
+    pub fn needs_changed_files(&self) -> bool {
+        self.scope_path.is_some() || self.type_path.is_some()
+    }
+
     pub fn validate(&self, message: &Message) -> Vec<Violation> {
         let mut results = Vec::new();
 
+        if let Some(rule) = &self.body_case {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
         if let Some(rule) = &self.body_empty {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.body_leading_blank {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.body_max_length {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.body_max_line_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.body_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.breaking_change_consistency {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.breaking_change_footer_required {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.description_case {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.description_empty {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.description_format {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.description_max_length {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.description_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_blank_line {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_duplicate {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_leading_blank {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_max_line_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_order {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_required {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_tokens {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footer_value_format {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.footers_empty {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.header_case {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.header_full_stop {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.header_max_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.header_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.header_trim {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.references_empty {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.references_pattern {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.revert_sha_empty {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.scope {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.scope_case {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.scope_empty {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.scope_format {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.scope_max_length {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.scope_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.scope_path {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.signed_off_by {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.subject_empty {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.subject_exclamation_mark {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.r#type {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.type_case {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.type_empty {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.type_format {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
 
         if let Some(rule) = &self.type_max_length {
-            if let Some(validation) = rule.validate(message) {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.type_min_length {
+            if let Some(validation) = rule.check(message) {
+                results.push(validation);
+            }
+        }
+
+        if let Some(rule) = &self.type_path {
+            if let Some(validation) = rule.check(message) {
                 results.push(validation);
             }
         }
@@ -191,21 +587,54 @@ impl Rules {
 impl Default for Rules {
     fn default() -> Self {
         Self {
+            body_case: None,
             body_empty: None,
+            body_leading_blank: None,
             body_max_length: None,
+            body_max_line_length: None,
+            body_min_length: None,
+            breaking_change_consistency: None,
+            breaking_change_footer_required: None,
+            description_case: None,
             description_empty: DescriptionEmpty::default().into(),
             description_format: None,
             description_max_length: None,
+            description_min_length: None,
+            footer_blank_line: None,
+            footer_duplicate: None,
+            footer_leading_blank: None,
+            footer_max_line_length: None,
+            footer_min_length: None,
+            footer_order: None,
+            footer_required: None,
+            footer_tokens: None,
+            footer_value_format: None,
             footers_empty: None,
+            header_case: None,
+            header_full_stop: None,
+            header_max_length: None,
+            header_min_length: None,
+            header_trim: None,
+            references_empty: None,
+            references_pattern: None,
+            revert_sha_empty: None,
             scope: None,
+            scope_case: None,
             scope_empty: None,
             scope_format: None,
             scope_max_length: None,
+            scope_min_length: None,
+            scope_path: None,
+            signed_off_by: None,
             subject_empty: SubjectEmpty::default().into(),
+            subject_exclamation_mark: None,
             r#type: None,
+            type_case: None,
             type_empty: TypeEmpty::default().into(),
             type_format: None,
             type_max_length: None,
+            type_min_length: None,
+            type_path: None,
         }
     }
 }
@@ -219,11 +648,69 @@ pub trait Rule: Default {
     /// The message to display when the rule fails.
     fn message(&self, message: &Message) -> String;
 
+    /// The message to display when the inverted rule fails.
+    fn inverted_message(&self, message: &Message) -> String;
+
     /// The level of the rule.
     const LEVEL: Level;
 
+    /// The applicable of the rule which `validate` checks.
+    /// For example, `body-empty` is `never` because it fails when the body is empty.
+    const APPLICABLE: Applicable = Applicable::Always;
+
+    /// The configured level of the rule.
+    fn level(&self) -> Level;
+
+    /// The configured applicable of the rule.
+    fn applicable(&self) -> Applicable;
+
+    /// Check if the given message has the part the rule checks.
+    /// The inverted rule is not checked if it is false such as the description-case rule
+    /// for the message without description.
+    fn has_target(&self, _message: &Message) -> bool {
+        true
+    }
+
+    /// The span of the part the rule checks such as the description for the description-case rule.
+    /// The violation of the inverted rule points at it.
+    fn span(&self, _message: &Message) -> Option<Span> {
+        None
+    }
+
     /// Validate the given text.
     fn validate(&self, message: &Message) -> Option<Violation>;
+
+    /// Validate the given text following the configured applicable.
+    /// If it differs from `APPLICABLE`, the result of `validate` is inverted.
+    fn check(&self, message: &Message) -> Option<Violation> {
+        if self.applicable() == Self::APPLICABLE {
+            return self.validate(message);
+        }
+
+        if !self.has_target(message) || self.validate(message).is_some() {
+            return None;
+        }
+
+        Some(Violation {
+            level: self.level(),
+            message: self.inverted_message(message),
+            span: self.span(message),
+        })
+    }
+}
+
+/// Applicable represents whether the rule is applied as is or inverted.
+///
+/// It is the same as the second element of `[level, applicable, value]` in commitlint.
+/// See: https://commitlint.js.org/reference/rules-configuration.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Applicable {
+    #[serde(rename = "always")]
+    Always,
+
+    #[serde(rename = "never")]
+    Never,
 }
 
 /// Level represents the level of a rule.
@@ -239,3 +726,491 @@ pub enum Level {
     #[serde(rename = "warning")]
     Warning,
 }
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Ignore => write!(f, "ignore"),
+            Level::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// LengthUnit represents how to count the length in the length rules.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LengthUnit {
+    /// Count the bytes in UTF-8.
+    #[serde(rename = "bytes")]
+    Bytes,
+
+    /// Count the Unicode scalar values.
+    #[default]
+    #[serde(rename = "chars")]
+    Chars,
+
+    /// Count the extended grapheme clusters such as `👨‍👩‍👧`.
+    #[serde(rename = "graphemes")]
+    Graphemes,
+
+    /// Count the columns in the terminal where East Asian wide characters take 2 columns.
+    #[serde(rename = "width")]
+    Width,
+}
+
+impl LengthUnit {
+    /// Measure the length of the text in the unit.
+    pub fn measure(&self, text: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => text.len(),
+            LengthUnit::Chars => text.chars().count(),
+            LengthUnit::Graphemes => text.graphemes(true).count(),
+            LengthUnit::Width => text.width(),
+        }
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthUnit::Bytes => write!(f, "bytes"),
+            LengthUnit::Chars => write!(f, "characters"),
+            LengthUnit::Graphemes => write!(f, "graphemes"),
+            LengthUnit::Width => write!(f, "columns"),
+        }
+    }
+}
+
+/// Pattern represents a regex option of the rules such as the format of the description-format rule.
+///
+/// It is compiled when the configuration is deserialized so that an invalid regex fails loading it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct Pattern(#[cfg_attr(feature = "schemars", schemars(with = "String"))] Regex);
+
+impl Pattern {
+    /// Compile the regex of the pattern.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Pattern)
+    }
+
+    /// Check if the text matches the pattern.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+impl TryFrom<String> for Pattern {
+    type Error = regex::Error;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Pattern::new(&pattern)
+    }
+}
+
+impl From<Pattern> for String {
+    fn from(pattern: Pattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.as_str())
+    }
+}
+
+/// OptionPattern represents an entry of the options of the scope and type rules.
+///
+/// The globs and the regexes are compiled when the configuration is deserialized
+/// so that an invalid one fails loading it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "RawOptionPattern", into = "RawOptionPattern")]
+pub enum OptionPattern {
+    /// Exact name such as `api`.
+    Name(String),
+
+    /// Glob such as `{ glob: "pkg-*" }`.
+    Glob(glob::Pattern),
+
+    /// Regex such as `{ pattern: "^pkg-[a-z]+$" }`.
+    /// It is compiled both as is and case-insensitively for the `ignore-case` option.
+    Regex { regex: Regex, ignore_case: Regex },
+}
+
+impl OptionPattern {
+    /// Compile the glob such as `pkg-*`.
+    pub fn glob(glob: &str) -> Result<Self, String> {
+        glob::Pattern::new(glob)
+            .map(OptionPattern::Glob)
+            .map_err(|err| format!("Invalid glob {:?}: {}", glob, err))
+    }
+
+    /// Compile the regex such as `^pkg-[a-z]+$`.
+    pub fn regex(pattern: &str) -> Result<Self, String> {
+        let regex = Regex::new(pattern).map_err(|err| err.to_string())?;
+        let ignore_case = RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|err| err.to_string())?;
+
+        Ok(OptionPattern::Regex { regex, ignore_case })
+    }
+
+    /// Check if the value matches the pattern.
+    pub fn matches(&self, value: &str, ignore_case: bool) -> bool {
+        match self {
+            OptionPattern::Name(name) if ignore_case => name.to_lowercase() == value.to_lowercase(),
+            OptionPattern::Name(name) => name == value,
+            OptionPattern::Glob(glob) => {
+                let options = glob::MatchOptions {
+                    case_sensitive: !ignore_case,
+                    ..Default::default()
+                };
+                glob.matches_with(value, options)
+            }
+            OptionPattern::Regex {
+                ignore_case: regex, ..
+            } if ignore_case => regex.is_match(value),
+            OptionPattern::Regex { regex, .. } => regex.is_match(value),
+        }
+    }
+
+    /// Check if the value matches any of the patterns.
+    pub fn matches_any(patterns: &[OptionPattern], value: &str, ignore_case: bool) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| pattern.matches(value, ignore_case))
+    }
+}
+
+/// RawOptionPattern represents an entry of the options of the scope and type rules
+/// as written in the configuration.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum RawOptionPattern {
+    /// Exact name such as `api`.
+    Name(String),
+
+    /// Glob such as `{ glob: "pkg-*" }`.
+    Glob { glob: String },
+
+    /// Regex such as `{ pattern: "^pkg-[a-z]+$" }`.
+    Regex { pattern: String },
+}
+
+impl TryFrom<RawOptionPattern> for OptionPattern {
+    type Error = String;
+
+    fn try_from(raw: RawOptionPattern) -> Result<Self, Self::Error> {
+        match raw {
+            RawOptionPattern::Name(name) => Ok(OptionPattern::Name(name)),
+            RawOptionPattern::Glob { glob } => OptionPattern::glob(&glob),
+            RawOptionPattern::Regex { pattern } => OptionPattern::regex(&pattern),
+        }
+    }
+}
+
+impl From<OptionPattern> for RawOptionPattern {
+    fn from(pattern: OptionPattern) -> Self {
+        match pattern {
+            OptionPattern::Name(name) => RawOptionPattern::Name(name),
+            OptionPattern::Glob(glob) => RawOptionPattern::Glob {
+                glob: glob.as_str().to_string(),
+            },
+            OptionPattern::Regex { regex, .. } => RawOptionPattern::Regex {
+                pattern: regex.as_str().to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for OptionPattern {
+    fn schema_name() -> String {
+        "OptionPattern".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        RawOptionPattern::json_schema(generator)
+    }
+}
+
+impl From<&str> for OptionPattern {
+    fn from(name: &str) -> Self {
+        OptionPattern::Name(name.to_string())
+    }
+}
+
+impl fmt::Display for OptionPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionPattern::Name(name) => write!(f, "{:?}", name),
+            OptionPattern::Glob(glob) => write!(f, "{{glob: {:?}}}", glob.as_str()),
+            OptionPattern::Regex { regex, .. } => write!(f, "{{pattern: {:?}}}", regex.as_str()),
+        }
+    }
+}
+
+/// Format the patterns for the violation message such as `["api", {glob: "pkg-*"}]`.
+fn format_patterns(patterns: &[OptionPattern]) -> String {
+    let patterns = patterns
+        .iter()
+        .map(OptionPattern::to_string)
+        .collect::<Vec<_>>();
+    format!("[{}]", patterns.join(", "))
+}
+
+/// PathGlob represents a glob of the paths changed by the commit such as `cli/**`.
+///
+/// `*` does not match `/` while `**` matches any directories.
+/// The glob ending with `/` such as `cli/` matches everything under the directory.
+/// It is compiled when the configuration is deserialized so that an invalid glob fails loading it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PathGlob {
+    /// Glob as written in the configuration.
+    source: String,
+
+    /// Compiled glob.
+    pattern: glob::Pattern,
+}
+
+impl PathGlob {
+    /// Compile the glob such as `cli/`.
+    pub fn new(glob: &str) -> Result<Self, String> {
+        let pattern = match glob.strip_suffix('/') {
+            Some(dir) => format!("{}/**", dir),
+            None => glob.to_string(),
+        };
+        let pattern = glob::Pattern::new(pattern.trim_start_matches('/'))
+            .map_err(|err| format!("Invalid path glob {:?}: {}", glob, err))?;
+
+        Ok(Self {
+            source: glob.to_string(),
+            pattern,
+        })
+    }
+
+    /// Check if the path matches the glob.
+    pub fn matches(&self, path: &str) -> bool {
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+
+        self.pattern.matches_with(path, options)
+    }
+
+    /// Check if the path matches any of the globs.
+    pub fn matches_any(globs: &[PathGlob], path: &str) -> bool {
+        globs.iter().any(|glob| glob.matches(path))
+    }
+}
+
+impl TryFrom<String> for PathGlob {
+    type Error = String;
+
+    fn try_from(glob: String) -> Result<Self, Self::Error> {
+        PathGlob::new(&glob)
+    }
+}
+
+impl From<PathGlob> for String {
+    fn from(glob: PathGlob) -> Self {
+        glob.source
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PathGlob {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(generator)
+    }
+}
+
+/// Bound represents whether the length rule checks the maximum or the minimum length.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Bound {
+    Max,
+    Min,
+}
+
+impl Bound {
+    /// Check if the length of the text is out of the bound.
+    fn is_exceeded(&self, text: &str, length: usize, unit: LengthUnit) -> bool {
+        match self {
+            Bound::Max => unit.measure(text) > length,
+            Bound::Min => unit.measure(text) < length,
+        }
+    }
+
+    /// The message to display when the part is out of the bound.
+    fn message(&self, part: &str, length: usize, unit: LengthUnit) -> String {
+        match self {
+            Bound::Max => format!("{} is longer than {} {}", part, length, unit),
+            Bound::Min => format!("{} is shorter than {} {}", part, length, unit),
+        }
+    }
+
+    /// The message to display when the part is in the bound for the inverted rule.
+    fn inverted_message(&self, part: &str, length: usize, unit: LengthUnit) -> String {
+        match self {
+            Bound::Max => format!("{} is not longer than {} {}", part, length, unit),
+            Bound::Min => format!("{} is not shorter than {} {}", part, length, unit),
+        }
+    }
+}
+
+/// Find the first line longer than the length in the span of the source and return its span.
+///
+/// The following lines are exempt because they cannot be wrapped.
+///
+/// * Lines of a single URL such as `https://example.com/very/long/path`
+/// * Lines in the fenced code blocks
+/// * Lines matching the ignore regex
+fn find_long_line(
+    source: &str,
+    span: Span,
+    length: usize,
+    unit: LengthUnit,
+    ignore: Option<&Pattern>,
+) -> Option<Span> {
+    let mut offset = span.start;
+    let mut in_code_block = false;
+    for line in source[span.start..span.end].split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let line = line.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        let is_url = !trimmed.contains(char::is_whitespace) && trimmed.contains("://");
+        if in_code_block || is_url || ignore.is_some_and(|pattern| pattern.is_match(line)) {
+            continue;
+        }
+
+        if unit.measure(line) > length {
+            return Some(Span::new(source, start, start + line.len()));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_unit() {
+        let text = "日本語👨‍👩‍👧";
+
+        assert_eq!(LengthUnit::Bytes.measure(text), 27);
+        assert_eq!(LengthUnit::Chars.measure(text), 8);
+        assert_eq!(LengthUnit::Graphemes.measure(text), 4);
+        assert_eq!(LengthUnit::Width.measure(text), 8);
+    }
+
+    #[test]
+    fn test_option_pattern() {
+        let name = OptionPattern::from("api");
+        assert!(name.matches("api", false));
+        assert!(!name.matches("API", false));
+        assert!(name.matches("API", true));
+
+        let glob = OptionPattern::glob("pkg-*").unwrap();
+        assert!(glob.matches("pkg-core", false));
+        assert!(!glob.matches("PKG-core", false));
+        assert!(glob.matches("PKG-core", true));
+
+        let regex = OptionPattern::regex("^pkg-[a-z]+$").unwrap();
+        assert!(regex.matches("pkg-core", false));
+        assert!(!regex.matches("pkg-1", false));
+        assert!(regex.matches("PKG-core", true));
+
+        assert!(OptionPattern::glob("[").is_err());
+        assert!(OptionPattern::regex("(").is_err());
+    }
+
+    #[test]
+    fn test_option_pattern_deserialize() {
+        let patterns: Vec<OptionPattern> =
+            serde_yaml::from_str("- api\n- glob: pkg-*\n- pattern: ^pkg-[a-z]+$").unwrap();
+
+        assert_eq!(
+            format_patterns(&patterns),
+            r#"["api", {glob: "pkg-*"}, {pattern: "^pkg-[a-z]+$"}]"#
+        );
+
+        let error = serde_yaml::from_str::<Vec<OptionPattern>>("- pattern: (").unwrap_err();
+        assert!(error.to_string().contains("regex parse error"));
+    }
+
+    #[test]
+    fn test_path_glob() {
+        let globs = vec![
+            PathGlob::new("cli/").unwrap(),
+            PathGlob::new("*.md").unwrap(),
+        ];
+        assert!(PathGlob::matches_any(&globs, "cli/src/git.rs"));
+        assert!(PathGlob::matches_any(&globs, "README.md"));
+        assert!(!PathGlob::matches_any(&globs, "web/README.md"));
+
+        let globs = vec![PathGlob::new("/**/*.md").unwrap()];
+        assert!(PathGlob::matches_any(&globs, "web/README.md"));
+        assert!(PathGlob::matches_any(&globs, "README.md"));
+
+        assert!(PathGlob::new("[").is_err());
+        assert_eq!(String::from(PathGlob::new("cli/").unwrap()), "cli/");
+    }
+
+    #[test]
+    fn test_bound() {
+        assert!(!Bound::Max.is_exceeded("abc", 3, LengthUnit::Chars));
+        assert!(Bound::Max.is_exceeded("abcd", 3, LengthUnit::Chars));
+        assert!(!Bound::Min.is_exceeded("abc", 3, LengthUnit::Chars));
+        assert!(Bound::Min.is_exceeded("ab", 3, LengthUnit::Chars));
+    }
+
+    #[test]
+    fn test_find_long_line() {
+        let source = "short\nthis line is long\nshort";
+        let span = Span::new(source, 0, source.len());
+
+        let line = find_long_line(source, span, 10, LengthUnit::Chars, None).unwrap();
+        assert_eq!(&source[line.start..line.end], "this line is long");
+        assert_eq!(line.line, 2);
+
+        assert!(find_long_line(source, span, 17, LengthUnit::Chars, None).is_none());
+    }
+
+    #[test]
+    fn test_find_long_line_with_exemptions() {
+        let source = "https://example.com/very/long/path
+```
+this line is long in the code block
+```
+Signed-off-by: this line is long";
+        let span = Span::new(source, 0, source.len());
+        let ignore = Pattern::new("^Signed-off-by: ").unwrap();
+
+        assert!(find_long_line(source, span, 10, LengthUnit::Chars, Some(&ignore)).is_none());
+        assert!(find_long_line(source, span, 10, LengthUnit::Chars, None).is_some());
+    }
+}
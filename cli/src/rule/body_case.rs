@@ -0,0 +1,136 @@
+use crate::{
+    case::{format_cases, Case},
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// BodyCase represents the body-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BodyCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Cases represents the allowed cases of the body.
+    /// The body must be in one of the cases.
+    cases: Vec<Case>,
+}
+
+/// BodyCase represents the body-case rule.
+impl Rule for BodyCase {
+    const NAME: &'static str = "body-case";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "body must be in one of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "body must not be in any of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !self.cases.is_empty() && message.body.as_deref().is_some_and(|text| !text.is_empty())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.body
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.cases.is_empty() {
+            return None;
+        }
+
+        if let Some(body) = message.body.as_deref().filter(|text| !text.is_empty()) {
+            if !self.cases.iter().any(|case| case.is(body)) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.body,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyCase.
+impl Default for BodyCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            cases: vec![Case::Sentence],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_case() {
+        let rule = BodyCase::default();
+        let message = Message::new(
+            "feat(cli): add new flag\n\nAdd the flag for the new feature.".to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_case() {
+        let rule = BodyCase::default();
+        let message = Message::new(
+            "feat(cli): add new flag\n\nadd the flag for the new feature.".to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body must be in one of cases: sentence-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_cases() {
+        let rule = BodyCase {
+            cases: vec![Case::Sentence, Case::Lower],
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nadd the flag for the new feature.".to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
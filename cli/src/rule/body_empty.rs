@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level};
 
 /// BodyEmpty represents the body-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,22 +16,43 @@ pub struct BodyEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
 }
 
 /// BodyEmpty represents the body-empty rule.
 impl Rule for BodyEmpty {
     const NAME: &'static str = "body-empty";
     const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
 
     fn message(&self, _message: &Message) -> String {
         "body is empty".to_string()
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        "body is not empty".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.body
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
         if message.body.is_none() {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: None,
             });
         }
 
@@ -40,6 +65,7 @@ impl Default for BodyEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
         }
     }
 }
@@ -54,14 +80,15 @@ mod tests {
         let message = Message {
             body: Some("Hello world".to_string()),
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction
 
 Hello world"
                 .to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -73,11 +100,12 @@ Hello world"
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
@@ -85,4 +113,21 @@ Hello world"
         assert_eq!(violation.clone().unwrap().level, Level::Error);
         assert_eq!(violation.unwrap().message, "body is empty".to_string());
     }
+
+    #[test]
+    fn test_always_empty_body() {
+        let rule = BodyEmpty {
+            applicable: Some(Applicable::Always),
+            ..Default::default()
+        };
+
+        let message = Message::new("chore: bump version".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message = Message::new("chore: bump version\n\nHello world".to_string());
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(violation.unwrap().message, "body is not empty".to_string());
+    }
 }
@@ -0,0 +1,109 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// BodyLeadingBlank represents the body-leading-blank rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BodyLeadingBlank {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// BodyLeadingBlank represents the body-leading-blank rule.
+impl Rule for BodyLeadingBlank {
+    const NAME: &'static str = "body-leading-blank";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "body must have leading blank line".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "body must not have leading blank line".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.body.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.body_leading_blank
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.has_target(message) && message.spans.body_leading_blank.is_none() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: message.spans.body,
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyLeadingBlank.
+impl Default for BodyLeadingBlank {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_leading_blank() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message::new("feat(cli): add new flag\n\nHello world".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_without_body() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_without_leading_blank() {
+        let rule = BodyLeadingBlank::default();
+        let message = Message::new("feat(cli): add new flag\nHello world".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body must have leading blank line".to_string()
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Bound, LengthUnit, Level};
 
 /// BodyMaxLength represents the body-max-length rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,8 +17,15 @@ pub struct BodyMaxLength {
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
 
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
     /// Length represents the maximum length of the body.
     length: usize,
+
+    /// Unit represents how to count the length of the body.
+    #[serde(default)]
+    unit: LengthUnit,
 }
 
 /// BodyMaxLength represents the body-max-length rule.
@@ -22,16 +33,37 @@ impl Rule for BodyMaxLength {
     const NAME: &'static str = "body-max-length";
     const LEVEL: Level = Level::Error;
 
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
     fn message(&self, _message: &Message) -> String {
-        format!("body is longer than {} characters", self.length)
+        Bound::Max.message("body", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Max.inverted_message("body", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.body.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.body
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         if let Some(body) = &message.body {
-            if body.len() >= self.length {
+            if Bound::Max.is_exceeded(body, self.length, self.unit) {
                 return Some(Violation {
                     level: self.level.unwrap_or(Self::LEVEL),
                     message: self.message(message),
+                    span: message.spans.body,
                 });
             }
         }
@@ -45,7 +77,9 @@ impl Default for BodyMaxLength {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
             length: 72,
+            unit: LengthUnit::default(),
         }
     }
 }
@@ -63,14 +97,15 @@ mod tests {
         let message = Message {
             body: Some("Hello world".to_string()),
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction
 
 Hey!"
                 .to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -85,11 +120,12 @@ Hey!"
         let message = Message {
             body: None,
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -104,14 +140,15 @@ Hey!"
         let message = Message {
             body: Some("Hello, I'm a long body".to_string()),
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction
 
 Hello, I'm a long body"
                 .to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
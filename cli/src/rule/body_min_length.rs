@@ -0,0 +1,118 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// BodyMinLength represents the body-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BodyMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the body.
+    length: usize,
+
+    /// Unit represents how to count the length of the body.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// BodyMinLength represents the body-min-length rule.
+impl Rule for BodyMinLength {
+    const NAME: &'static str = "body-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("body", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("body", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.body.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.body
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(body) = &message.body {
+            if Bound::Min.is_exceeded(body, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.body,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BodyMinLength.
+impl Default for BodyMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_body() {
+        let rule = BodyMinLength {
+            length: 11,
+            ..Default::default()
+        };
+        let message = Message::new("fix: typo\n\nHello world".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_body() {
+        let rule = BodyMinLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message::new("fix: typo\n\nHello world".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "body is shorter than 20 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    git::is_breaking_change_token,
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// BreakingChangeConsistency represents the breaking-change-consistency rule.
+///
+/// The exclamation mark in the subject and the `BREAKING CHANGE` footer
+/// must be either both present or both absent.
+///
+/// Note that this is stricter than the Conventional Commits which allows the
+/// exclamation mark without the footer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BreakingChangeConsistency {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// BreakingChangeConsistency represents the breaking-change-consistency rule.
+impl Rule for BreakingChangeConsistency {
+    const NAME: &'static str = "breaking-change-consistency";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        if message.breaking {
+            "exclamation mark is used but BREAKING CHANGE footer is missing".to_string()
+        } else {
+            "BREAKING CHANGE footer is used but exclamation mark is missing".to_string()
+        }
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "exclamation mark and BREAKING CHANGE footer are used together".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.breaking || !message.breaking_changes.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.breaking
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if message.breaking == message.breaking_changes.is_empty() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: if message.breaking {
+                    message.spans.breaking
+                } else {
+                    message
+                        .footers
+                        .iter()
+                        .find(|footer| is_breaking_change_token(&footer.token))
+                        .map(|footer| footer.span)
+                },
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BreakingChangeConsistency.
+impl Default for BreakingChangeConsistency {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_breaking() {
+        let rule = BreakingChangeConsistency::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_consistent_breaking() {
+        let rule = BreakingChangeConsistency::default();
+        let message = Message::new(
+            "feat(cli)!: remove old flag
+
+BREAKING CHANGE: old flag is removed"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_missing_footer() {
+        let rule = BreakingChangeConsistency::default();
+        let message = Message::new("feat(cli)!: remove old flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.clone().unwrap().message,
+            "exclamation mark is used but BREAKING CHANGE footer is missing".to_string()
+        );
+
+        let span = violation.unwrap().span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "!");
+        assert_eq!((span.line, span.column), (1, 10));
+    }
+
+    #[test]
+    fn test_missing_exclamation_mark() {
+        let rule = BreakingChangeConsistency::default();
+        let message = Message::new(
+            "feat(cli): remove old flag
+
+BREAKING-CHANGE: old flag is removed"
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "BREAKING CHANGE footer is used but exclamation mark is missing".to_string()
+        );
+    }
+}
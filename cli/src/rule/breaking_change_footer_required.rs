@@ -0,0 +1,125 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// BreakingChangeFooterRequired represents the breaking-change-footer-required rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BreakingChangeFooterRequired {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// BreakingChangeFooterRequired represents the breaking-change-footer-required rule.
+impl Rule for BreakingChangeFooterRequired {
+    const NAME: &'static str = "breaking-change-footer-required";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "BREAKING CHANGE footer is required when exclamation mark is used".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "BREAKING CHANGE footer is not allowed when exclamation mark is used".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.breaking
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.breaking
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if message.breaking && message.breaking_changes.is_empty() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: message.spans.breaking.or(message.spans.subject),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of BreakingChangeFooterRequired.
+impl Default for BreakingChangeFooterRequired {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_exclamation_mark() {
+        let rule = BreakingChangeFooterRequired::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_with_breaking_change_footer() {
+        let rule = BreakingChangeFooterRequired::default();
+        let message = Message::new(
+            "feat(cli)!: remove old flag
+
+BREAKING CHANGE: old flag is removed"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_without_breaking_change_footer() {
+        let rule = BreakingChangeFooterRequired::default();
+        let message = Message::new("feat(cli)!: remove old flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "BREAKING CHANGE footer is required when exclamation mark is used".to_string()
+        );
+    }
+
+    #[test]
+    fn test_never_without_exclamation_mark() {
+        let rule = BreakingChangeFooterRequired {
+            applicable: Some(Applicable::Never),
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.check(&message).is_none());
+    }
+}
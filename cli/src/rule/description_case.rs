@@ -0,0 +1,159 @@
+use crate::{
+    case::{format_cases, Case},
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// DescriptionCase represents the description-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DescriptionCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Cases represents the allowed cases of the description.
+    /// The description must be in one of the cases.
+    cases: Vec<Case>,
+}
+
+/// DescriptionCase represents the description-case rule.
+impl Rule for DescriptionCase {
+    const NAME: &'static str = "description-case";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "description must be in one of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "description must not be in any of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !self.cases.is_empty()
+            && message
+                .description
+                .as_deref()
+                .is_some_and(|text| !text.is_empty())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.description
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.cases.is_empty() {
+            return None;
+        }
+
+        if let Some(description) = message
+            .description
+            .as_deref()
+            .filter(|text| !text.is_empty())
+        {
+            if !self.cases.iter().any(|case| case.is(description)) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.description,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of DescriptionCase.
+impl Default for DescriptionCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            cases: vec![Case::Lower],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_case() {
+        let rule = DescriptionCase::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_case() {
+        let rule = DescriptionCase::default();
+        let message = Message::new("feat(cli): Add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "description must be in one of cases: lower-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_cases() {
+        let rule = DescriptionCase {
+            cases: vec![Case::Lower, Case::Sentence],
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): Add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_never_case() {
+        let rule = DescriptionCase {
+            applicable: Some(Applicable::Never),
+            cases: vec![Case::Upper],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message = Message::new("feat(cli): ADD NEW FLAG".to_string());
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().span.unwrap().column, 12);
+        assert_eq!(
+            violation.unwrap().message,
+            "description must not be in any of cases: upper-case".to_string()
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level};
 
 /// DescriptionEmpty represents the subject-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,26 +16,48 @@ pub struct DescriptionEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
 }
 
 /// DescriptionEmpty represents the description-empty rule.
 impl Rule for DescriptionEmpty {
     const NAME: &'static str = "description-empty";
     const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
 
     fn message(&self, _message: &Message) -> String {
         "description is empty or missing space in the beginning".to_string()
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        "description is not empty".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.description
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
         match message.description {
             None => Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: message.spans.subject,
             }),
             Some(ref desc) if desc.is_empty() => Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: message.spans.description,
             }),
             _ => None,
         }
@@ -43,6 +69,7 @@ impl Default for DescriptionEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
         }
     }
 }
@@ -57,11 +84,12 @@ mod tests {
         let message = Message {
             body: None,
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -73,11 +101,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "(scope):".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
@@ -95,11 +124,12 @@ mod tests {
         let message = Message {
             body: None,
             description: Some("".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "(scope):".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
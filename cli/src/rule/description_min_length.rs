@@ -0,0 +1,118 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// DescriptionMinLength represents the description-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DescriptionMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the description.
+    length: usize,
+
+    /// Unit represents how to count the length of the description.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// DescriptionMinLength represents the description-min-length rule.
+impl Rule for DescriptionMinLength {
+    const NAME: &'static str = "description-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("description", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("description", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.description.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.description
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(desc) = &message.description {
+            if Bound::Min.is_exceeded(desc, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.description,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of DescriptionMinLength.
+impl Default for DescriptionMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_description() {
+        let rule = DescriptionMinLength {
+            length: 1,
+            ..Default::default()
+        };
+        let message = Message::new("fix: x".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_description() {
+        let rule = DescriptionMinLength {
+            length: 10,
+            ..Default::default()
+        };
+        let message = Message::new("fix: x".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "description is shorter than 10 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// FooterBlankLine represents the footer-blank-line rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterBlankLine {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+impl FooterBlankLine {
+    /// Find the first blank lines between the consecutive footers.
+    ///
+    /// Note that the blank lines in the value of a footer such as the paragraphs
+    /// of a long `BREAKING CHANGE` note are not counted.
+    fn find_blank_lines(&self, message: &Message) -> Option<Span> {
+        message.footers.windows(2).find_map(|footers| {
+            let start = footers[0].span.end;
+            let gap = &message.raw[start..footers[1].span.start];
+            let first = gap.find('\n')?;
+            let last = gap.rfind('\n')?;
+
+            (first < last).then(|| Span::new(&message.raw, start + first + 1, start + last))
+        })
+    }
+}
+
+/// FooterBlankLine represents the footer-blank-line rule.
+impl Rule for FooterBlankLine {
+    const NAME: &'static str = "footer-blank-line";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "footers have blank lines".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "footers have no blank lines".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.footers.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_blank_lines(message).map(|span| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: Some(span),
+        })
+    }
+}
+
+/// Default implementation of FooterBlankLine.
+impl Default for FooterBlankLine {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_blank_lines() {
+        let rule = FooterBlankLine::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Refs: #123
+Signed-off-by: Alice <alice@example.com>
+"
+            .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let rule = FooterBlankLine::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Refs: #123
+
+\t
+Signed-off-by: Alice <alice@example.com>"
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.level, Level::Error);
+        assert_eq!(violation.message, "footers have blank lines".to_string());
+        let span = violation.span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "\n\t");
+        assert_eq!(span.line, 4);
+    }
+
+    #[test]
+    fn test_always_blank_lines() {
+        let rule = FooterBlankLine {
+            applicable: Some(Applicable::Always),
+            ..Default::default()
+        };
+
+        let message =
+            Message::new("feat(cli): add new flag\n\nRefs: #123\n\nRefs: #456".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123\nRefs: #456".to_string());
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "footers have no blank lines".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multi_paragraph_value() {
+        let rule = FooterBlankLine::default();
+        let message = Message::new(
+            "feat(cli)!: remove old flag
+
+BREAKING CHANGE: the old flag is removed.
+
+  Use the new flag instead.
+Refs: #123"
+                .to_string(),
+        );
+
+        assert_eq!(message.footers.len(), 2);
+        assert!(rule.validate(&message).is_none());
+    }
+}
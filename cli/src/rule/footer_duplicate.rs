@@ -0,0 +1,135 @@
+use crate::{
+    message::{Footer, Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// FooterDuplicate represents the footer-duplicate rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterDuplicate {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+impl FooterDuplicate {
+    /// Find the footer identical to any of the previous footers.
+    /// Note that the tokens are compared case-insensitively as git trailers.
+    fn find_duplicate<'a>(&self, message: &'a Message) -> Option<&'a Footer> {
+        message.footers.iter().enumerate().find_map(|(i, footer)| {
+            message.footers[..i]
+                .iter()
+                .any(|previous| {
+                    previous.token.eq_ignore_ascii_case(&footer.token)
+                        && previous.value == footer.value
+                })
+                .then_some(footer)
+        })
+    }
+}
+
+/// FooterDuplicate represents the footer-duplicate rule.
+impl Rule for FooterDuplicate {
+    const NAME: &'static str = "footer-duplicate";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_duplicate(message) {
+            Some(footer) => format!(
+                "footer {}{}{} is duplicated",
+                footer.token, footer.separator, footer.value
+            ),
+            None => "footer is duplicated".to_string(),
+        }
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "footers are not duplicated".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.footers.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_duplicate(message).map(|footer| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: Some(footer.span),
+        })
+    }
+}
+
+/// Default implementation of FooterDuplicate.
+impl Default for FooterDuplicate {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_footers() {
+        let rule = FooterDuplicate::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Co-authored-by: Alice <alice@example.com>
+Co-authored-by: Bob <bob@example.com>"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_duplicated_footers() {
+        let rule = FooterDuplicate::default();
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Signed-off-by: Alice <alice@example.com>
+Refs: #123
+signed-off-by: Alice <alice@example.com>"
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.level, Level::Error);
+        assert_eq!(
+            violation.message,
+            "footer signed-off-by: Alice <alice@example.com> is duplicated".to_string()
+        );
+        assert_eq!(violation.span.unwrap().line, 5);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{find_long_line, Applicable, LengthUnit, Level, Pattern};
+
+/// FooterMaxLineLength represents the footer-max-line-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterMaxLineLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the maximum length of each line of the footers.
+    length: usize,
+
+    /// Unit represents how to count the length of the lines.
+    #[serde(default)]
+    unit: LengthUnit,
+
+    /// Ignore represents the regex of the lines exempt from the rule
+    /// in addition to the lines of a single URL and in the fenced code blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore: Option<Pattern>,
+}
+
+/// FooterMaxLineLength represents the footer-max-line-length rule.
+impl Rule for FooterMaxLineLength {
+    const NAME: &'static str = "footer-max-line-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "footer has a line longer than {} {}",
+            self.length, self.unit
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "footer has no line longer than {} {}",
+            self.length, self.unit
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.footers.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        message
+            .footers
+            .iter()
+            .map(|footer| footer.span)
+            .find_map(|span| {
+                find_long_line(
+                    &message.raw,
+                    span,
+                    self.length,
+                    self.unit,
+                    self.ignore.as_ref(),
+                )
+            })
+            .map(|span| Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: Some(span),
+            })
+    }
+}
+
+/// Default implementation of FooterMaxLineLength.
+impl Default for FooterMaxLineLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 100,
+            unit: LengthUnit::default(),
+            ignore: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_lines() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message =
+            Message::new("feat(cli): add new flag\n\nRefs: #123\nAcked-by: Keke".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_long_line() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\nSigned-off-by: Keke <keke@example.com>"
+                .to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.clone().unwrap().message,
+            "footer has a line longer than 20 characters".to_string()
+        );
+        assert_eq!(violation.unwrap().span.unwrap().line, 4);
+    }
+
+    #[test]
+    fn test_long_url_line() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\n  https://example.com/very/long/path"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_ignored_line() {
+        let rule = FooterMaxLineLength {
+            length: 20,
+            ignore: Some(Pattern::new("^Signed-off-by: ").unwrap()),
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\nSigned-off-by: Keke <keke@example.com>"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
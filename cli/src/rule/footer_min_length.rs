@@ -0,0 +1,119 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// FooterMinLength represents the footer-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the footers.
+    length: usize,
+
+    /// Unit represents how to count the length of the footers.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// FooterMinLength represents the footer-min-length rule.
+impl Rule for FooterMinLength {
+    const NAME: &'static str = "footer-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("footer", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("footer", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.footers.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let (Some(first), Some(last)) = (message.footers.first(), message.footers.last()) {
+            let footers = &message.raw[first.span.start..last.span.end];
+            if Bound::Min.is_exceeded(footers, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: Some(Span::new(&message.raw, first.span.start, last.span.end)),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of FooterMinLength.
+impl Default for FooterMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_footers() {
+        let rule = FooterMinLength {
+            length: 25,
+            ..Default::default()
+        };
+        let message = Message::new("fix: typo\n\nRefs: #123\nAcked-by: Keke".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_footers() {
+        let rule = FooterMinLength {
+            length: 26,
+            ..Default::default()
+        };
+        let message = Message::new("fix: typo\n\nRefs: #123\nAcked-by: Keke".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "footer is shorter than 26 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    message::{Footer, Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// Wildcard of the tokens which matches any token not in the order.
+const ANY_TOKEN: &str = "*";
+
+/// FooterOrder represents the footer-order rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterOrder {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Order represents the order of the footer tokens such as `["*", "Signed-off-by"]`.
+    /// `*` represents the tokens not in the order, which are not checked if it is absent.
+    #[serde(default)]
+    order: Vec<String>,
+}
+
+impl FooterOrder {
+    /// Get the position of the token in the order.
+    /// Note that the tokens are compared case-insensitively as git trailers.
+    fn rank(&self, token: &str) -> Option<usize> {
+        self.order
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case(token))
+            .or_else(|| self.order.iter().position(|t| t == ANY_TOKEN))
+    }
+
+    /// Find the footer put after the footer which must come after it.
+    fn find_misplaced<'a>(&self, message: &'a Message) -> Option<(&'a Footer, &'a Footer)> {
+        let mut last: Option<(&Footer, usize)> = None;
+        for footer in &message.footers {
+            let rank = match self.rank(&footer.token) {
+                Some(rank) => rank,
+                None => continue,
+            };
+
+            match last {
+                Some((previous, previous_rank)) if previous_rank > rank => {
+                    return Some((footer, previous));
+                }
+                _ => last = Some((footer, rank)),
+            }
+        }
+
+        None
+    }
+}
+
+/// FooterOrder represents the footer-order rule.
+impl Rule for FooterOrder {
+    const NAME: &'static str = "footer-order";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_misplaced(message) {
+            Some((footer, previous)) => format!(
+                "footer {} must come before {}. The order is {:?}",
+                footer.token, previous.token, self.order
+            ),
+            None => format!("footers are not in the order {:?}", self.order),
+        }
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!("footers are in the order {:?}", self.order)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message
+            .footers
+            .iter()
+            .filter(|footer| self.rank(&footer.token).is_some())
+            .count()
+            > 1
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_misplaced(message).map(|(footer, _)| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: Some(footer.span),
+        })
+    }
+}
+
+/// Default implementation of FooterOrder.
+impl Default for FooterOrder {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            order: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordered_footers() {
+        let rule = FooterOrder {
+            order: vec!["Refs".to_string(), "Reviewed-by".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Refs: #123
+Link: https://example.com
+Reviewed-by: Bob <bob@example.com>
+reviewed-by: Carol <carol@example.com>"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_misplaced_footer() {
+        let rule = FooterOrder {
+            order: vec!["Refs".to_string(), "Reviewed-by".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nReviewed-by: Bob <bob@example.com>\nRefs: #123".to_string(),
+        );
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.level, Level::Error);
+        assert_eq!(
+            violation.message,
+            "footer Refs must come before Reviewed-by. The order is [\"Refs\", \"Reviewed-by\"]"
+                .to_string()
+        );
+        assert_eq!(violation.span.unwrap().line, 4);
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let rule = FooterOrder {
+            order: vec!["*".to_string(), "Signed-off-by".to_string()],
+            ..Default::default()
+        };
+
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\nSigned-off-by: Alice <alice@example.com>"
+                .to_string(),
+        );
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new(
+            "feat(cli): add new flag\n\nSigned-off-by: Alice <alice@example.com>\nRefs: #123"
+                .to_string(),
+        );
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "footer Refs must come before Signed-off-by. The order is [\"*\", \"Signed-off-by\"]"
+                .to_string()
+        );
+    }
+}
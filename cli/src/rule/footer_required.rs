@@ -0,0 +1,230 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// Wildcard of the types which matches any commit.
+const ANY_TYPE: &str = "*";
+
+/// RequiredFooter represents the footer token required in the commit message.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum RequiredFooter {
+    /// Token such as `Reviewed-by`.
+    Token(String),
+
+    /// Any of the tokens such as `[Fixes, Refs]`.
+    AnyOf(Vec<String>),
+}
+
+impl RequiredFooter {
+    /// Check if the message has the required footer.
+    /// Note that the tokens are matched case-insensitively as git trailers.
+    fn is_present(&self, message: &Message) -> bool {
+        let has = |token: &String| {
+            message
+                .footers
+                .iter()
+                .any(|footer| footer.token.eq_ignore_ascii_case(token))
+        };
+
+        match self {
+            RequiredFooter::Token(token) => has(token),
+            RequiredFooter::AnyOf(tokens) => tokens.iter().any(has),
+        }
+    }
+}
+
+impl fmt::Display for RequiredFooter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequiredFooter::Token(token) => write!(f, "{}", token),
+            RequiredFooter::AnyOf(tokens) => write!(f, "{}", tokens.join(" or ")),
+        }
+    }
+}
+
+/// FooterRequired represents the footer-required rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterRequired {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Types represents the required footers of each type such as `fix: [[Fixes, Refs]]`.
+    /// The footers of `*` are required for any commit.
+    #[serde(default)]
+    types: BTreeMap<String, Vec<RequiredFooter>>,
+}
+
+impl FooterRequired {
+    /// Get the footers required for the type of the message followed by the ones for any type.
+    fn required(&self, message: &Message) -> Vec<&RequiredFooter> {
+        let footers = message
+            .r#type
+            .as_ref()
+            .filter(|t| t.as_str() != ANY_TYPE)
+            .and_then(|t| self.types.get(t));
+
+        footers
+            .into_iter()
+            .chain(self.types.get(ANY_TYPE))
+            .flatten()
+            .collect()
+    }
+
+    /// Get the required footers missing in the message.
+    fn missing(&self, message: &Message) -> Vec<&RequiredFooter> {
+        self.required(message)
+            .into_iter()
+            .filter(|footer| !footer.is_present(message))
+            .collect()
+    }
+}
+
+/// FooterRequired represents the footer-required rule.
+impl Rule for FooterRequired {
+    const NAME: &'static str = "footer-required";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        let missing = self
+            .missing(message)
+            .iter()
+            .map(|footer| footer.to_string())
+            .collect::<Vec<_>>();
+
+        format!("required footers are missing: {}", missing.join(", "))
+    }
+
+    fn inverted_message(&self, message: &Message) -> String {
+        let required = self
+            .required(message)
+            .iter()
+            .map(|footer| footer.to_string())
+            .collect::<Vec<_>>();
+
+        format!("footers are not allowed: {}", required.join(", "))
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !self.required(message).is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.missing(message).is_empty() {
+            return None;
+        }
+
+        Some(Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: None,
+        })
+    }
+}
+
+/// Default implementation of FooterRequired.
+impl Default for FooterRequired {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            types: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> FooterRequired {
+        serde_yaml::from_str(
+            r#"
+types:
+  fix:
+    - [Fixes, Refs]
+  feat:
+    - Reviewed-by
+  "*":
+    - Signed-off-by
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_present_footers() {
+        let message = Message::new(
+            "fix(cli): fix typo\n\nrefs: #123\nSigned-off-by: Alice <alice@example.com>"
+                .to_string(),
+        );
+
+        assert!(rule().validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_missing_footers() {
+        let message = Message::new("fix(cli): fix typo\n\nReviewed-by: Bob".to_string());
+
+        let violation = rule().validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "required footers are missing: Fixes or Refs, Signed-off-by".to_string()
+        );
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let message = Message::new("chore: bump version".to_string());
+
+        let violation = rule().validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "required footers are missing: Signed-off-by".to_string()
+        );
+    }
+
+    #[test]
+    fn test_no_required_footers() {
+        let rule = FooterRequired {
+            types: BTreeMap::from([(
+                "feat".to_string(),
+                vec![RequiredFooter::Token("Reviewed-by".to_string())],
+            )]),
+            ..Default::default()
+        };
+        let message = Message::new("fix(cli): fix typo".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
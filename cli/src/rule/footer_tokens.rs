@@ -0,0 +1,234 @@
+use crate::{
+    git::is_breaking_change_token,
+    message::{Footer, Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{format_patterns, Applicable, Level, OptionPattern};
+
+/// FooterTokens represents the footer-tokens rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterTokens {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Allow represents the allowed footer tokens such as `Refs`.
+    /// If it is empty, any tokens are allowed except the denied ones.
+    /// Note that `BREAKING CHANGE` is always allowed unless it is denied.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    allow: Vec<OptionPattern>,
+
+    /// Deny represents the denied footer tokens such as `Change-Id`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    deny: Vec<OptionPattern>,
+
+    /// Ignore case represents whether the tokens are matched case-insensitively.
+    #[serde(default, rename = "ignore-case")]
+    ignore_case: bool,
+}
+
+impl FooterTokens {
+    /// Check if the token is denied.
+    fn is_denied(&self, token: &str) -> bool {
+        OptionPattern::matches_any(&self.deny, token, self.ignore_case)
+    }
+
+    /// Check if the token is allowed.
+    fn is_allowed(&self, token: &str) -> bool {
+        !self.is_denied(token)
+            && (self.allow.is_empty()
+                || is_breaking_change_token(token)
+                || OptionPattern::matches_any(&self.allow, token, self.ignore_case))
+    }
+
+    /// Find the footer whose token is not allowed.
+    fn find_disallowed<'a>(&self, message: &'a Message) -> Option<&'a Footer> {
+        message
+            .footers
+            .iter()
+            .find(|footer| !self.is_allowed(&footer.token))
+    }
+}
+
+/// FooterTokens represents the footer-tokens rule.
+impl Rule for FooterTokens {
+    const NAME: &'static str = "footer-tokens";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        let token = self
+            .find_disallowed(message)
+            .map_or("", |footer| footer.token.as_str());
+
+        if self.is_denied(token) {
+            return format!(
+                "footer token {} is not allowed. {} are not allowed",
+                token,
+                format_patterns(&self.deny)
+            );
+        }
+
+        format!(
+            "footer token {} is not allowed. Only {} are allowed",
+            token,
+            format_patterns(&self.allow)
+        )
+    }
+
+    fn inverted_message(&self, message: &Message) -> String {
+        let tokens = message
+            .footers
+            .iter()
+            .map(|footer| footer.token.as_str())
+            .collect::<Vec<_>>();
+
+        format!("footer tokens {} are allowed", tokens.join(","))
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.footers.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_disallowed(message).map(|footer| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: Some(footer.span),
+        })
+    }
+}
+
+/// Default implementation of FooterTokens.
+impl Default for FooterTokens {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            allow: vec![],
+            deny: vec![],
+            ignore_case: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_tokens() {
+        let rule = FooterTokens {
+            allow: vec!["Refs".into(), "Signed-off-by".into()],
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\nBREAKING CHANGE: remove old flag\nSigned-off-by: Alice <alice@example.com>".to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_disallowed_token() {
+        let rule = FooterTokens {
+            allow: vec!["Refs".into()],
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123\nFixes #456".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.level, Level::Error);
+        assert_eq!(
+            violation.message,
+            "footer token Fixes is not allowed. Only [\"Refs\"] are allowed".to_string()
+        );
+        let span = violation.span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "Fixes #456");
+    }
+
+    #[test]
+    fn test_denied_token() {
+        let rule = FooterTokens {
+            deny: vec![OptionPattern::glob("Change-*").unwrap()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new(
+            "feat(cli): add new flag\n\nChange-Id: I0123456789abcdef0123456789abcdef01234567"
+                .to_string(),
+        );
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "footer token Change-Id is not allowed. [{glob: \"Change-*\"}] are not allowed"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        let rule = FooterTokens {
+            allow: vec!["Co-authored-by".into()],
+            ..Default::default()
+        };
+        let message = Message::new(
+            "feat(cli): add new flag\n\nCo-Authored-By: Alice <alice@example.com>".to_string(),
+        );
+        assert!(rule.validate(&message).is_some());
+
+        let rule = FooterTokens {
+            ignore_case: true,
+            ..rule
+        };
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_never_tokens() {
+        let rule = FooterTokens {
+            applicable: Some(Applicable::Never),
+            allow: vec!["Refs".into()],
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag\n\nFixes #456".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123".to_string());
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "footer tokens Refs are allowed".to_string()
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    message::{Footer, Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level, Pattern};
+
+/// FooterValueFormat represents the footer-value-format rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FooterValueFormat {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Formats represents the regex of the value of each footer token such as `Refs: "^PROJ-\d+$"`.
+    /// Note that the tokens are matched case-insensitively as git trailers.
+    #[serde(default)]
+    formats: BTreeMap<String, Pattern>,
+}
+
+impl FooterValueFormat {
+    /// Get the format of the footer token.
+    fn format(&self, token: &str) -> Option<&Pattern> {
+        self.formats
+            .iter()
+            .find(|(t, _)| t.eq_ignore_ascii_case(token))
+            .map(|(_, format)| format)
+    }
+
+    /// Find the footer whose value does not match the format of its token.
+    fn find_unmatched<'a>(&self, message: &'a Message) -> Option<&'a Footer> {
+        message.footers.iter().find(|footer| {
+            self.format(&footer.token)
+                .is_some_and(|format| !format.is_match(&footer.value))
+        })
+    }
+}
+
+/// FooterValueFormat represents the footer-value-format rule.
+impl Rule for FooterValueFormat {
+    const NAME: &'static str = "footer-value-format";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        match self.find_unmatched(message) {
+            Some(footer) => format!(
+                "footer {} value does not match format: {}",
+                footer.token,
+                self.format(&footer.token)
+                    .map_or(String::new(), Pattern::to_string)
+            ),
+            None => "footer value does not match format".to_string(),
+        }
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "footer values match formats".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message
+            .footers
+            .iter()
+            .any(|footer| self.format(&footer.token).is_some())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message
+            .footers
+            .iter()
+            .find(|footer| self.format(&footer.token).is_some())
+            .map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_unmatched(message).map(|footer| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: Some(footer.span),
+        })
+    }
+}
+
+/// Default implementation of FooterValueFormat.
+impl Default for FooterValueFormat {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            formats: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> FooterValueFormat {
+        FooterValueFormat {
+            formats: BTreeMap::from([
+                (
+                    "Co-authored-by".to_string(),
+                    Pattern::new(r"^[^<>]+ <[^<>@\s]+@[^<>\s]+>$").unwrap(),
+                ),
+                ("Refs".to_string(), Pattern::new(r"^PROJ-\d+$").unwrap()),
+                (
+                    "Change-Id".to_string(),
+                    Pattern::new(r"^I[0-9a-f]{40}$").unwrap(),
+                ),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_valid_values() {
+        let message = Message::new(
+            "feat(cli): add new flag
+
+Refs: PROJ-123
+Change-Id: I0123456789abcdef0123456789abcdef01234567
+co-authored-by: Alice <alice@example.com>
+Signed-off-by: anything"
+                .to_string(),
+        );
+
+        assert!(rule().validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: PROJ-123\nCo-authored-by: Alice".to_string(),
+        );
+
+        let violation = rule().validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.level, Level::Error);
+        assert_eq!(
+            violation.message,
+            r"footer Co-authored-by value does not match format: ^[^<>]+ <[^<>@\s]+@[^<>\s]+>$"
+                .to_string()
+        );
+        let span = violation.span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "Co-authored-by: Alice");
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        let rule = serde_yaml::from_str::<FooterValueFormat>("formats:\n  Refs: \"(\"\n");
+        assert!(rule.is_err());
+        assert!(rule.unwrap_err().to_string().contains("regex parse error"));
+    }
+
+    #[test]
+    fn test_never_format() {
+        let rule = FooterValueFormat {
+            applicable: Some(Applicable::Never),
+            ..rule()
+        };
+
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message =
+            Message::new("feat(cli): add new flag\n\nFixes: #1\nRefs: PROJ-123".to_string());
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        let span = violation.unwrap().span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "Refs: PROJ-123");
+    }
+}
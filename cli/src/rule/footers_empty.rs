@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level};
 
 /// FootersEmpty represents the footer-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,22 +16,43 @@ pub struct FootersEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
 }
 
 /// FooterEmpty represents the footer-empty rule.
 impl Rule for FootersEmpty {
     const NAME: &'static str = "footers-empty";
     const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
 
     fn message(&self, _message: &Message) -> String {
         "footers are empty".to_string()
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        "footers are not empty".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.footers.first().map(|footer| footer.span)
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.footers.is_none() {
+        if message.footers.is_empty() {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: None,
             });
         }
 
@@ -40,13 +65,14 @@ impl Default for FootersEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use crate::message::Footer;
 
     use super::*;
 
@@ -54,13 +80,15 @@ mod tests {
     fn test_non_empty_footer() {
         let rule = FootersEmpty::default();
 
-        let mut f = HashMap::new();
-        f.insert("Link".to_string(), "hello".to_string());
-
         let message = Message {
             body: Some("Hello world".to_string()),
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: Some(f),
+            footers: vec![Footer {
+                token: "Link".to_string(),
+                separator: ": ".to_string(),
+                value: "hello".to_string(),
+                ..Default::default()
+            }],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction
 
@@ -68,8 +96,9 @@ Hello world
 
 Link: hello"
                 .to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -81,11 +110,12 @@ Link: hello"
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
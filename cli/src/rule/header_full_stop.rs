@@ -0,0 +1,131 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// HeaderFullStop represents the header-full-stop rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HeaderFullStop {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Character represents the full stop character at the end of the header.
+    /// If it is not specified, `.` is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    character: Option<String>,
+}
+
+impl HeaderFullStop {
+    /// Get the full stop character.
+    fn character(&self) -> &str {
+        self.character.as_deref().unwrap_or(".")
+    }
+}
+
+/// HeaderFullStop represents the header-full-stop rule.
+impl Rule for HeaderFullStop {
+    const NAME: &'static str = "header-full-stop";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!("header ends with full stop {:?}", self.character())
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!("header does not end with full stop {:?}", self.character())
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.subject.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.subject
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(header) = &message.subject {
+            if header.trim_end().ends_with(self.character()) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.subject,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderFullStop.
+impl Default for HeaderFullStop {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            character: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_without_full_stop() {
+        let rule = HeaderFullStop::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_header_with_full_stop() {
+        let rule = HeaderFullStop::default();
+        let message = Message::new("feat(cli): add new flag.".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header ends with full stop \".\"".to_string()
+        );
+    }
+
+    #[test]
+    fn test_header_with_custom_full_stop() {
+        let rule = HeaderFullStop {
+            character: Some("。".to_string()),
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag.".to_string());
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new("feat(cli): 新しいフラグを追加。".to_string());
+        assert!(rule.validate(&message).is_some());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// HeaderMinLength represents the header-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HeaderMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the header.
+    length: usize,
+
+    /// Unit represents how to count the length of the header.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// HeaderMinLength represents the header-min-length rule.
+impl Rule for HeaderMinLength {
+    const NAME: &'static str = "header-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("header", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("header", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.subject.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.subject
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(header) = &message.subject {
+            if Bound::Min.is_exceeded(header, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.subject,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderMinLength.
+impl Default for HeaderMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_header() {
+        let rule = HeaderMinLength {
+            length: 23,
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_header() {
+        let rule = HeaderMinLength {
+            length: 24,
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header is shorter than 24 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// HeaderTrim represents the header-trim rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HeaderTrim {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// HeaderTrim represents the header-trim rule.
+impl Rule for HeaderTrim {
+    const NAME: &'static str = "header-trim";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        let header = message.subject.as_deref().unwrap_or_default();
+        match (
+            header.starts_with(char::is_whitespace),
+            header.ends_with(char::is_whitespace),
+        ) {
+            (true, true) => "header has leading and trailing whitespace".to_string(),
+            (true, false) => "header has leading whitespace".to_string(),
+            _ => "header has trailing whitespace".to_string(),
+        }
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "header has no leading or trailing whitespace".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.subject.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.subject
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(header) = &message.subject {
+            if header.trim() != header {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.subject,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of HeaderTrim.
+impl Default for HeaderTrim {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trimmed_header() {
+        let rule = HeaderTrim::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_header_with_leading_whitespace() {
+        let rule = HeaderTrim::default();
+        let message = Message::new("  feat(cli): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "header has leading whitespace".to_string()
+        );
+    }
+
+    #[test]
+    fn test_header_with_trailing_whitespace() {
+        let rule = HeaderTrim::default();
+        let message = Message::new("feat(cli): add new flag \n\nHello world".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "header has trailing whitespace".to_string()
+        );
+    }
+}
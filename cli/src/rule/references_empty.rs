@@ -0,0 +1,135 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// ReferencesEmpty represents the references-empty rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ReferencesEmpty {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Types represents the types which require the references.
+    /// If the option is empty, all types require the references.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    types: Vec<String>,
+}
+
+/// ReferencesEmpty represents the references-empty rule.
+impl Rule for ReferencesEmpty {
+    const NAME: &'static str = "references-empty";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "references are empty".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "references are not empty".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        self.types.is_empty() || matches!(&message.r#type, Some(t) if self.types.contains(t))
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.references.first().map(|reference| reference.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !self.types.is_empty() && !matches!(&message.r#type, Some(t) if self.types.contains(t)) {
+            return None;
+        }
+
+        if message.references.is_empty() {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: None,
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ReferencesEmpty.
+impl Default for ReferencesEmpty {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            types: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_empty_references() {
+        let rule = ReferencesEmpty::default();
+        let message = Message::new("fix(cli): handle empty input\n\nCloses #12".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_empty_references() {
+        let rule = ReferencesEmpty::default();
+        let message = Message::new("fix(cli): handle empty input".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "references are empty".to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_references_with_other_type() {
+        let rule = ReferencesEmpty {
+            types: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new("docs: update README".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_empty_references_with_listed_type() {
+        let rule = ReferencesEmpty {
+            types: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        };
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_some());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level, Pattern};
+
+/// ReferencesPattern represents the references-pattern rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ReferencesPattern {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Pattern represents the regex which at least one reference must match
+    /// such as `^PROJ-\d+$`. It is matched against the reference as written.
+    pattern: Option<Pattern>,
+
+    /// Types represents the types which require the reference.
+    /// If the option is empty, all types require the reference.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    types: Vec<String>,
+}
+
+/// ReferencesPattern represents the references-pattern rule.
+impl Rule for ReferencesPattern {
+    const NAME: &'static str = "references-pattern";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "found no reference matching pattern: {}",
+            self.pattern.as_ref().unwrap()
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "found reference matching pattern: {}",
+            self.pattern.as_ref().unwrap()
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        self.pattern.is_some()
+            && (self.types.is_empty()
+                || matches!(&message.r#type, Some(t) if self.types.contains(t)))
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.references.first().map(|reference| reference.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if !self.types.is_empty() && !matches!(&message.r#type, Some(t) if self.types.contains(t)) {
+            return None;
+        }
+
+        if let Some(pattern) = &self.pattern {
+            if !message
+                .references
+                .iter()
+                .any(|reference| pattern.is_match(&reference.raw))
+            {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.references.first().map(|reference| reference.span),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ReferencesPattern.
+impl Default for ReferencesPattern {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            pattern: None,
+            types: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::{HeaderParser, ReferenceParser};
+
+    fn message(raw: &str) -> Message {
+        let parser = ReferenceParser::new(&[r"\bPROJ-\d+\b".to_string()]).unwrap();
+        Message::with_parser(raw.to_string(), &HeaderParser::default(), &parser)
+    }
+
+    #[test]
+    fn test_matched_reference() {
+        let rule = ReferencesPattern {
+            pattern: Some(Pattern::new(r"^PROJ-\d+$").unwrap()),
+            ..Default::default()
+        };
+        let message = message("feat(cli): add new flag\n\nRefs: #12, PROJ-123");
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unmatched_reference() {
+        let rule = ReferencesPattern {
+            pattern: Some(Pattern::new(r"^PROJ-\d+$").unwrap()),
+            ..Default::default()
+        };
+        let message = message("feat(cli): add new flag\n\nCloses #12");
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            r"found no reference matching pattern: ^PROJ-\d+$".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unmatched_reference_with_other_type() {
+        let rule = ReferencesPattern {
+            pattern: Some(Pattern::new(r"^PROJ-\d+$").unwrap()),
+            types: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        };
+        let message = message("docs: update README");
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let rule = serde_yaml::from_str::<ReferencesPattern>(r#"pattern: "(""#);
+        assert!(rule.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// RevertShaEmpty represents the revert-sha-empty rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RevertShaEmpty {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// RevertShaEmpty represents the revert-sha-empty rule.
+impl Rule for RevertShaEmpty {
+    const NAME: &'static str = "revert-sha-empty";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "revert commit has no \"This reverts commit <sha>\" line".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "revert commit has \"This reverts commit <sha>\" line".to_string()
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.revert.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.subject
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if matches!(&message.revert, Some(revert) if revert.sha.is_none()) {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: message.spans.subject,
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of RevertShaEmpty.
+impl Default for RevertShaEmpty {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_revert() {
+        let rule = RevertShaEmpty::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_revert_with_sha() {
+        let rule = RevertShaEmpty::default();
+        let message = Message::new(
+            "Revert \"feat(cli): add new flag\"\n\nThis reverts commit 1234567890abcdef1234567890abcdef12345678.".to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_revert_without_sha() {
+        let rule = RevertShaEmpty::default();
+        let message = Message::new("Revert \"feat(cli): add new flag\"".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "revert commit has no \"This reverts commit <sha>\" line".to_string()
+        );
+    }
+}
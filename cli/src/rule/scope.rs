@@ -1,7 +1,14 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use std::path::Path;
+
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+    workspace::ScopeSource,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{format_patterns, Applicable, Level, OptionPattern};
 
 /// Scope represents the subject-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,13 +20,73 @@ pub struct Scope {
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
 
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
     /// Options represents the options of the rule.
     /// If the option is empty, it means that no scope is allowed.
-    options: Vec<String>,
+    /// Each option is either a name, a glob such as `{ glob: "pkg-*" }` or a regex such as `{ pattern: "^pkg-[a-z]+$" }`.
+    options: Vec<OptionPattern>,
+
+    /// Ignore case represents whether the options are matched case-insensitively.
+    #[serde(default, rename = "ignore-case")]
+    ignore_case: bool,
 
     /// Optional scope.
     /// If true, even if the scope is not present, it is allowed.
     optional: bool,
+
+    /// Max number represents the maximum number of the scopes such as `feat(api,cli): ...`.
+    #[serde(rename = "max-number")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_number: Option<usize>,
+
+    /// Sources represents where the scopes are discovered from in addition to the options.
+    /// The scopes are resolved when the configuration is loaded.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<ScopeSource>,
+}
+
+impl Scope {
+    /// Resolve the scopes of the sources relative to the directory and add them to the options.
+    pub fn resolve(&mut self, cwd: &Path) -> Result<(), String> {
+        for source in &self.sources {
+            for scope in source.scopes(cwd)? {
+                let exists = self
+                    .options
+                    .iter()
+                    .any(|option| matches!(option, OptionPattern::Name(name) if *name == scope));
+                if !exists {
+                    self.options.push(OptionPattern::Name(scope));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if the scope is allowed.
+    ///
+    /// The nested scope such as `api/users` is allowed if its parent such as `api` is allowed.
+    /// Note that `/` is the delimiter of the multiple scopes unless `nested-scopes` is enabled.
+    fn is_allowed(&self, scope: &str) -> bool {
+        if scope.is_empty() {
+            return self.options.is_empty();
+        }
+
+        let mut path = scope;
+        loop {
+            if OptionPattern::matches_any(&self.options, path, self.ignore_case) {
+                return true;
+            }
+
+            match path.rfind('/') {
+                Some(index) => path = &path[..index],
+                None => return false,
+            }
+        }
+    }
 }
 
 /// Scope represents the scope rule.
@@ -27,35 +94,70 @@ impl Rule for Scope {
     const NAME: &'static str = "scope";
     const LEVEL: Level = Level::Error;
 
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
     fn message(&self, message: &Message) -> String {
         if self.options.is_empty() {
             return "scopes are not allowed".to_string();
         }
 
+        if let Some(max_number) = self.max_number.filter(|n| message.scope.len() > *n) {
+            return format!(
+                "found {} scopes but only {} are allowed",
+                message.scope.len(),
+                max_number
+            );
+        }
+
+        format!(
+            "scope {} is not allowed. Only {} are allowed",
+            message
+                .scope
+                .iter()
+                .find(|scope| !self.is_allowed(scope))
+                .map_or("", String::as_str),
+            format_patterns(&self.options)
+        )
+    }
+
+    fn inverted_message(&self, message: &Message) -> String {
         format!(
-            "scope {} is not allowed. Only {:?} are allowed",
-            message.scope.as_ref().unwrap_or(&"".to_string()),
-            self.options
+            "scope {} is not allowed. {} are not allowed",
+            message.scope.join(","),
+            format_patterns(&self.options)
         )
     }
 
+    fn has_target(&self, message: &Message) -> bool {
+        !message.scope.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.scope
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
-        match &message.scope {
-            None if self.options.is_empty() || self.optional => {
+        if message.scope.is_empty() {
+            if self.options.is_empty() || self.optional {
                 return None;
             }
-            Some(scope) if scope.is_empty() && self.options.is_empty() => {
+        } else if self.max_number.is_none_or(|n| message.scope.len() <= n) {
+            let allowed = message.scope.iter().all(|scope| self.is_allowed(scope));
+            if allowed {
                 return None;
             }
-            Some(scope) if self.options.contains(scope) => {
-                return None;
-            }
-            _ => {}
         }
 
         Some(Violation {
             level: self.level.unwrap_or(Self::LEVEL),
             message: self.message(message),
+            span: message.spans.scope.or(message.spans.subject),
         })
     }
 }
@@ -65,8 +167,12 @@ impl Default for Scope {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
             optional: false,
             options: vec![],
+            ignore_case: false,
+            max_number: None,
+            sources: vec![],
         }
     }
 }
@@ -85,11 +191,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: Some("".to_string()),
+                scope: vec!["".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -103,11 +210,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: None,
+                scope: vec![],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -121,11 +229,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("web".to_string()),
+                scope: vec!["web".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -143,18 +252,19 @@ mod tests {
         #[test]
         fn test_empty_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: Some("".to_string()),
+                scope: vec!["".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -169,18 +279,19 @@ mod tests {
         #[test]
         fn test_none_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: None,
+                scope: vec![],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -195,18 +306,19 @@ mod tests {
         #[test]
         fn test_valid_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("web".to_string()),
+                scope: vec!["web".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             assert!(rule.validate(&message).is_none());
@@ -215,18 +327,19 @@ mod tests {
         #[test]
         fn test_invalid_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(invalid): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("invalid".to_string()),
+                scope: vec!["invalid".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -241,7 +354,7 @@ mod tests {
         #[test]
         fn test_optional_scope_with_non_empty_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 optional: true,
                 ..Default::default()
             };
@@ -249,11 +362,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(invalid): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("invalid".to_string()),
+                scope: vec!["invalid".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -268,7 +382,7 @@ mod tests {
         #[test]
         fn test_optional_scope_with_empty_scope() {
             let rule = Scope {
-                options: vec!["api".to_string(), "web".to_string()],
+                options: vec!["api".into(), "web".into()],
                 optional: true,
                 ..Default::default()
             };
@@ -276,15 +390,179 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat: broadcast $destroy event on scope destruction".to_string(),
-                scope: None,
+                scope: vec![],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
             assert!(violation.is_none());
         }
     }
+
+    mod multiple_scopes {
+        use super::*;
+
+        #[test]
+        fn test_valid_scopes() {
+            let rule = Scope {
+                options: vec!["api".into(), "cli".into()],
+                ..Default::default()
+            };
+            let message = Message::new("feat(api,cli): add new flag".to_string());
+
+            assert!(rule.validate(&message).is_none());
+        }
+
+        #[test]
+        fn test_invalid_scopes() {
+            let rule = Scope {
+                options: vec!["api".into(), "cli".into()],
+                ..Default::default()
+            };
+            let message = Message::new("feat(api,web): add new flag".to_string());
+
+            let violation = rule.validate(&message);
+            assert!(violation.is_some());
+            assert_eq!(
+                violation.unwrap().message,
+                "scope web is not allowed. Only [\"api\", \"cli\"] are allowed".to_string()
+            );
+        }
+
+        #[test]
+        fn test_too_many_scopes() {
+            let rule = Scope {
+                options: vec!["api".into(), "cli".into()],
+                max_number: Some(1),
+                ..Default::default()
+            };
+            let message = Message::new("feat(api,cli): add new flag".to_string());
+
+            let violation = rule.validate(&message);
+            assert!(violation.is_some());
+            assert_eq!(
+                violation.unwrap().message,
+                "found 2 scopes but only 1 are allowed".to_string()
+            );
+        }
+
+        #[test]
+        fn test_nested_scope() {
+            let rule = Scope {
+                options: vec!["api".into()],
+                ..Default::default()
+            };
+            let message = Message {
+                scope: vec!["api/users".to_string()],
+                ..Default::default()
+            };
+            assert!(rule.validate(&message).is_none());
+
+            let message = Message {
+                scope: vec!["cli/users".to_string()],
+                ..Default::default()
+            };
+            assert!(rule.validate(&message).is_some());
+        }
+    }
+
+    mod patterns {
+        use super::*;
+
+        #[test]
+        fn test_glob() {
+            let rule = Scope {
+                options: vec![OptionPattern::glob("pkg-*").unwrap()],
+                ..Default::default()
+            };
+
+            let message = Message::new("feat(pkg-core): add new flag".to_string());
+            assert!(rule.validate(&message).is_none());
+
+            let message = Message::new("feat(PKG-core): add new flag".to_string());
+            let violation = rule.validate(&message);
+            assert!(violation.is_some());
+            assert_eq!(
+                violation.unwrap().message,
+                "scope PKG-core is not allowed. Only [{glob: \"pkg-*\"}] are allowed".to_string()
+            );
+        }
+
+        #[test]
+        fn test_regex() {
+            let rule = Scope {
+                options: vec!["api".into(), OptionPattern::regex("^pkg-[a-z]+$").unwrap()],
+                ..Default::default()
+            };
+
+            let message = Message::new("feat(api,pkg-core): add new flag".to_string());
+            assert!(rule.validate(&message).is_none());
+
+            let message = Message::new("feat(pkg-1): add new flag".to_string());
+            let violation = rule.validate(&message);
+            assert!(violation.is_some());
+            assert_eq!(
+                violation.unwrap().message,
+                "scope pkg-1 is not allowed. Only [\"api\", {pattern: \"^pkg-[a-z]+$\"}] are allowed"
+                    .to_string()
+            );
+        }
+
+        #[test]
+        fn test_ignore_case() {
+            let rule = Scope {
+                options: vec!["api".into(), OptionPattern::glob("pkg-*").unwrap()],
+                ignore_case: true,
+                ..Default::default()
+            };
+
+            let message = Message::new("feat(API,PKG-core): add new flag".to_string());
+            assert!(rule.validate(&message).is_none());
+        }
+
+        #[test]
+        fn test_invalid_pattern() {
+            let rule =
+                serde_yaml::from_str::<Scope>("optional: false\noptions:\n  - pattern: \"(\"\n");
+            assert!(rule.is_err());
+            assert!(rule.unwrap_err().to_string().contains("regex parse error"));
+        }
+    }
+
+    mod sources {
+        use super::*;
+
+        #[test]
+        fn test_resolve() {
+            let mut rule = Scope {
+                options: vec!["cli".into(), "web".into()],
+                sources: vec![ScopeSource::CargoWorkspace],
+                ..Default::default()
+            };
+            rule.resolve(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+                .unwrap();
+
+            assert_eq!(
+                format_patterns(&rule.options),
+                r#"["cli", "web", "schema"]"#
+            );
+
+            let message = Message::new("feat(schema): add new rule".to_string());
+            assert!(rule.validate(&message).is_none());
+        }
+
+        #[test]
+        fn test_resolve_missing_file() {
+            let mut rule = Scope {
+                sources: vec![ScopeSource::PnpmWorkspace],
+                ..Default::default()
+            };
+
+            assert!(rule.resolve(Path::new(env!("CARGO_MANIFEST_DIR"))).is_err());
+        }
+    }
 }
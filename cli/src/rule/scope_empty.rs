@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level};
 
 /// ScopeEmpty represents the subject-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,22 +16,44 @@ pub struct ScopeEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
 }
 
 /// ScopeEmpty represents the scope-empty rule.
 impl Rule for ScopeEmpty {
     const NAME: &'static str = "scope-empty";
     const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
 
     fn message(&self, _message: &Message) -> String {
         "scope is empty".to_string()
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        "scope is not empty".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.scope
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
-        if message.scope.is_none() {
+        // Note that the empty scope such as the trailing one of `feat(api,): ...` is also empty.
+        if message.scope.is_empty() || message.scope.iter().any(String::is_empty) {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: message.spans.subject,
             });
         }
 
@@ -40,6 +66,7 @@ impl Default for ScopeEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
         }
     }
 }
@@ -54,11 +81,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -70,11 +98,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat: broadcast $destroy event on scope destruction".to_string(),
-            scope: None,
+            scope: vec![],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
@@ -89,11 +118,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(): broadcast $destroy event on scope destruction".to_string(),
-            scope: None,
+            scope: vec![],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
@@ -101,4 +131,14 @@ mod tests {
         assert_eq!(violation.clone().unwrap().level, Level::Error);
         assert_eq!(violation.unwrap().message, "scope is empty".to_string());
     }
+
+    #[test]
+    fn test_trailing_empty_scope() {
+        let rule = ScopeEmpty::default();
+        let message = Message::new("feat(api,): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.unwrap().message, "scope is empty".to_string());
+    }
 }
@@ -0,0 +1,118 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// ScopeMinLength represents the description-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScopeMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the scope.
+    length: usize,
+
+    /// Unit represents how to count the length of the scope.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// ScopeMinLength represents the scope-min-length rule.
+impl Rule for ScopeMinLength {
+    const NAME: &'static str = "scope-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("scope", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("scope", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.scope.is_empty()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.scope
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        for scope in &message.scope {
+            if Bound::Min.is_exceeded(scope, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.scope,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of ScopeMinLength.
+impl Default for ScopeMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_scope() {
+        let rule = ScopeMinLength {
+            length: 3,
+            ..Default::default()
+        };
+        let message = Message::new("fix(cli): typo".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_scope() {
+        let rule = ScopeMinLength {
+            length: 4,
+            ..Default::default()
+        };
+        let message = Message::new("fix(cli): typo".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope is shorter than 4 characters".to_string()
+        );
+    }
+}
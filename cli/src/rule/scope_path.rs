@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level, PathGlob};
+
+/// ScopePath represents the scope-path rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScopePath {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Paths represents the path globs of each scope such as `cli: ["cli/**"]`.
+    /// The scopes not listed here are not checked.
+    #[serde(default)]
+    paths: BTreeMap<String, Vec<PathGlob>>,
+}
+
+impl ScopePath {
+    /// Find the scope which does not match any of the changed files.
+    fn find_unmatched<'a>(&self, message: &'a Message, files: &[String]) -> Option<&'a String> {
+        message.scope.iter().find(|scope| {
+            self.paths
+                .get(*scope)
+                .is_some_and(|globs| !files.iter().any(|file| PathGlob::matches_any(globs, file)))
+        })
+    }
+
+    /// Suggest the scope whose globs match the most changed files.
+    fn suggest(&self, files: &[String]) -> Option<&String> {
+        self.paths
+            .iter()
+            .map(|(scope, globs)| {
+                let count = files
+                    .iter()
+                    .filter(|file| PathGlob::matches_any(globs, file))
+                    .count();
+                (scope, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .fold(
+                None,
+                |best: Option<(&String, usize)>, (scope, count)| match best {
+                    Some((_, best_count)) if best_count >= count => best,
+                    _ => Some((scope, count)),
+                },
+            )
+            .map(|(scope, _)| scope)
+    }
+}
+
+/// ScopePath represents the scope-path rule.
+impl Rule for ScopePath {
+    const NAME: &'static str = "scope-path";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        let files = message.changed_files.as_deref().unwrap_or_default();
+        let scope = self
+            .find_unmatched(message, files)
+            .map_or("", String::as_str);
+
+        match self.suggest(files) {
+            Some(suggested) => format!(
+                "scope {} does not match the changed files. Suggested scope is {}",
+                scope, suggested
+            ),
+            None => format!("scope {} does not match the changed files", scope),
+        }
+    }
+
+    fn inverted_message(&self, message: &Message) -> String {
+        format!(
+            "scope {} matches the changed files",
+            message.scope.join(",")
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !message.scope.is_empty()
+            && message
+                .changed_files
+                .as_ref()
+                .is_some_and(|files| !files.is_empty())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.scope
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let files = match message.changed_files.as_deref() {
+            Some(files) if !files.is_empty() => files,
+            _ => return None,
+        };
+
+        self.find_unmatched(message, files).map(|_| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: message.spans.scope.or(message.spans.subject),
+        })
+    }
+}
+
+/// Default implementation of ScopePath.
+impl Default for ScopePath {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            paths: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> ScopePath {
+        ScopePath {
+            paths: BTreeMap::from([
+                ("cli".to_string(), vec![PathGlob::new("cli/**").unwrap()]),
+                ("web".to_string(), vec![PathGlob::new("web/").unwrap()]),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    fn message(raw: &str, files: &[&str]) -> Message {
+        Message {
+            changed_files: Some(files.iter().map(|file| file.to_string()).collect()),
+            ..Message::new(raw.to_string())
+        }
+    }
+
+    #[test]
+    fn test_matched_scope() {
+        let message = message("feat(cli): add new flag", &["cli/src/args.rs", "README.md"]);
+        assert!(rule().validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_unmatched_scope() {
+        let message = message("feat(cli): add new flag", &["web/src/index.md"]);
+
+        let violation = rule().validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "scope cli does not match the changed files. Suggested scope is web".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unmatched_scope_without_suggestion() {
+        let message = message("feat(cli): add new flag", &["README.md"]);
+
+        let violation = rule().validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "scope cli does not match the changed files".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_scope() {
+        let message = message("feat(schema): add new rule", &["README.md"]);
+        assert!(rule().validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_changed_files() {
+        let message = Message::new("feat(cli): add new flag".to_string());
+        assert!(rule().validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        let rule = serde_yaml::from_str::<ScopePath>("paths:\n  cli: [\"cli/[\"]\n");
+        assert!(rule.is_err());
+        assert!(rule.unwrap_err().to_string().contains("Invalid path glob"));
+    }
+}
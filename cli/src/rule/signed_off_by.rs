@@ -0,0 +1,289 @@
+use crate::{
+    message::{Footer, Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// Token of the sign-off footer of the Developer Certificate of Origin.
+/// See: https://developercertificate.org
+const SIGNED_OFF_BY: &str = "Signed-off-by";
+
+/// SignedOffBy represents the signed-off-by rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SignedOffBy {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Last represents whether the sign-off must be the last footer.
+    #[serde(default)]
+    last: bool,
+}
+
+impl SignedOffBy {
+    /// Find the problem of the sign-off and the span to point.
+    ///
+    /// When the message is read from git, one of the sign-offs must match the author
+    /// in the format of `Name <email>`.
+    fn find_problem(&self, message: &Message) -> Option<(String, Option<Span>)> {
+        let sign_offs = message
+            .footers
+            .iter()
+            .filter(|footer| is_sign_off(footer))
+            .collect::<Vec<_>>();
+
+        let first = match sign_offs.first() {
+            Some(first) => first,
+            None => return Some((format!("{} footer is missing", SIGNED_OFF_BY), None)),
+        };
+
+        if self.last {
+            let last = message.footers.last();
+            if !last.is_some_and(is_sign_off) {
+                return Some((
+                    format!("{} footer is not the last footer", SIGNED_OFF_BY),
+                    last.map(|footer| footer.span),
+                ));
+            }
+        }
+
+        if let Some(commit) = &message.commit {
+            let author = &commit.author;
+            if !sign_offs
+                .iter()
+                .any(|footer| is_identity(footer, &author.name, &author.email))
+            {
+                return Some((
+                    format!(
+                        "{} footer does not match the author {} <{}>",
+                        SIGNED_OFF_BY, author.name, author.email
+                    ),
+                    Some(first.span),
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/// Check if the footer is the sign-off.
+fn is_sign_off(footer: &Footer) -> bool {
+    footer.token.eq_ignore_ascii_case(SIGNED_OFF_BY)
+}
+
+/// Check if the footer value is the identity such as `Alice <alice@example.com>`.
+/// Note that the email is compared case-insensitively.
+fn is_identity(footer: &Footer, name: &str, email: &str) -> bool {
+    match footer.value.trim().strip_suffix('>') {
+        Some(value) => match value.rsplit_once('<') {
+            Some((n, e)) => n.trim() == name && e.trim().eq_ignore_ascii_case(email),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+/// SignedOffBy represents the signed-off-by rule.
+impl Rule for SignedOffBy {
+    const NAME: &'static str = "signed-off-by";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        self.find_problem(message).map_or_else(
+            || format!("{} footer is required", SIGNED_OFF_BY),
+            |(message, _)| message,
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!("{} footer exists", SIGNED_OFF_BY)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.footers.iter().any(is_sign_off)
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message
+            .footers
+            .iter()
+            .find(|footer| is_sign_off(footer))
+            .map(|footer| footer.span)
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        self.find_problem(message).map(|(msg, span)| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: msg,
+            span,
+        })
+    }
+}
+
+/// Default implementation of SignedOffBy.
+impl Default for SignedOffBy {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            last: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::{Commit, Signature};
+
+    #[test]
+    fn test_signed_off() {
+        let rule = SignedOffBy::default();
+        let message = Message::new(
+            "feat(cli): add new flag\n\nSigned-off-by: Alice <alice@example.com>\nRefs: #123"
+                .to_string(),
+        );
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_missing_sign_off() {
+        let rule = SignedOffBy::default();
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "Signed-off-by footer is missing".to_string()
+        );
+    }
+
+    #[test]
+    fn test_last_sign_off() {
+        let rule = SignedOffBy {
+            last: true,
+            ..Default::default()
+        };
+
+        let message = Message::new(
+            "feat(cli): add new flag\n\nRefs: #123\nSigned-off-by: Alice <alice@example.com>"
+                .to_string(),
+        );
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message::new(
+            "feat(cli): add new flag\n\nSigned-off-by: Alice <alice@example.com>\nRefs: #123"
+                .to_string(),
+        );
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(
+            violation.message,
+            "Signed-off-by footer is not the last footer".to_string()
+        );
+        let span = violation.span.unwrap();
+        assert_eq!(&message.raw[span.start..span.end], "Refs: #123");
+    }
+
+    #[test]
+    fn test_author() {
+        let rule = SignedOffBy::default();
+        let commit = Commit {
+            author: Signature {
+                name: "Alice".to_string(),
+                email: "alice@example.com".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let message = Message {
+            commit: Some(commit.clone()),
+            ..Message::new(
+                "feat(cli): add new flag\n\nSigned-off-by: Bob <bob@example.com>\nSigned-off-by: Alice <Alice@Example.com>".to_string(),
+            )
+        };
+        assert!(rule.validate(&message).is_none());
+
+        let message = Message {
+            commit: Some(commit),
+            ..Message::new(
+                "feat(cli): add new flag\n\nSigned-off-by: Bob <bob@example.com>".to_string(),
+            )
+        };
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "Signed-off-by footer does not match the author Alice <alice@example.com>".to_string()
+        );
+    }
+
+    #[test]
+    fn test_never_sign_off() {
+        let rule = SignedOffBy {
+            applicable: Some(Applicable::Never),
+            last: true,
+            ..Default::default()
+        };
+        let commit = Commit {
+            author: Signature {
+                name: "Alice".to_string(),
+                email: "alice@example.com".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let message = Message::new("feat(cli): add new flag\n\nRefs: #123".to_string());
+        assert!(rule.check(&message).is_none());
+
+        let message = Message {
+            commit: Some(commit.clone()),
+            ..Message::new(
+                "feat(cli): add new flag\n\nSigned-off-by: Bob <bob@example.com>\nRefs: #123"
+                    .to_string(),
+            )
+        };
+        assert!(rule.check(&message).is_none());
+
+        let message = Message {
+            commit: Some(commit),
+            ..Message::new(
+                "feat(cli): add new flag\n\nRefs: #123\nSigned-off-by: Alice <alice@example.com>"
+                    .to_string(),
+            )
+        };
+        let violation = rule.check(&message);
+        assert!(violation.is_some());
+        let violation = violation.unwrap();
+        assert_eq!(violation.message, "Signed-off-by footer exists".to_string());
+        let span = violation.span.unwrap();
+        assert_eq!(
+            &message.raw[span.start..span.end],
+            "Signed-off-by: Alice <alice@example.com>"
+        );
+    }
+}
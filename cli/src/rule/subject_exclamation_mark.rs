@@ -0,0 +1,98 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// SubjectExclamationMark represents the subject-exclamation-mark rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SubjectExclamationMark {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+}
+
+/// SubjectExclamationMark represents the subject-exclamation-mark rule.
+impl Rule for SubjectExclamationMark {
+    const NAME: &'static str = "subject-exclamation-mark";
+    const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        "subject has exclamation mark before the colon".to_string()
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        "subject has no exclamation mark before the colon".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.breaking
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if message.breaking {
+            return Some(Violation {
+                level: self.level.unwrap_or(Self::LEVEL),
+                message: self.message(message),
+                span: message.spans.breaking.or(message.spans.subject),
+            });
+        }
+
+        None
+    }
+}
+
+/// Default implementation of SubjectExclamationMark.
+impl Default for SubjectExclamationMark {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_exclamation_mark() {
+        let rule = SubjectExclamationMark::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_with_exclamation_mark() {
+        let rule = SubjectExclamationMark::default();
+        let message = Message::new("feat(cli)!: remove old flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "subject has exclamation mark before the colon".to_string()
+        );
+    }
+}
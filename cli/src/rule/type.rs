@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{format_patterns, Applicable, Level, OptionPattern};
 
 /// Type represents the subject-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,27 +17,60 @@ pub struct Type {
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
 
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
     /// Options represents the options of the rule.
     /// If the option is empty, it means that no Type is allowed.
-    options: Vec<String>,
+    /// Each option is either a name, a glob such as `{ glob: "feat*" }` or a regex such as `{ pattern: "^(feat|fix)$" }`.
+    options: Vec<OptionPattern>,
+
+    /// Ignore case represents whether the options are matched case-insensitively.
+    #[serde(default, rename = "ignore-case")]
+    ignore_case: bool,
 }
 
 /// Type represents the type rule.
 impl Rule for Type {
     const NAME: &'static str = "type";
     const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
     fn message(&self, message: &Message) -> String {
         if self.options.is_empty() {
             return "types are not allowed".to_string();
         }
 
         format!(
-            "type {} is not allowed. Only {:?} are allowed",
+            "type {} is not allowed. Only {} are allowed",
             message.r#type.as_ref().unwrap_or(&"".to_string()),
-            self.options
+            format_patterns(&self.options)
         )
     }
 
+    fn inverted_message(&self, message: &Message) -> String {
+        format!(
+            "type {} is not allowed. {} are not allowed",
+            message.r#type.as_ref().unwrap_or(&"".to_string()),
+            format_patterns(&self.options)
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.r#type.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
         match &message.r#type {
             None if self.options.is_empty() => {
@@ -42,7 +79,7 @@ impl Rule for Type {
             Some(r#type) if r#type.is_empty() && self.options.is_empty() => {
                 return None;
             }
-            Some(r#type) if self.options.contains(r#type) => {
+            Some(r#type) if OptionPattern::matches_any(&self.options, r#type, self.ignore_case) => {
                 return None;
             }
             _ => {}
@@ -51,6 +88,7 @@ impl Rule for Type {
         Some(Violation {
             level: self.level.unwrap_or(Self::LEVEL),
             message: self.message(message),
+            span: message.spans.r#type.or(message.spans.subject),
         })
     }
 }
@@ -60,7 +98,9 @@ impl Default for Type {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
             options: vec![],
+            ignore_case: false,
         }
     }
 }
@@ -79,11 +119,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: Some("".to_string()),
+                scope: vec!["".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -97,11 +138,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: None,
+                scope: vec![],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -115,11 +157,12 @@ mod tests {
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("web".to_string()),
+                scope: vec!["web".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -137,18 +180,19 @@ mod tests {
         #[test]
         fn test_empty_type() {
             let rule = Type {
-                options: vec!["feat".to_string(), "chore".to_string()],
+                options: vec!["feat".into(), "chore".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: Some("".to_string()),
+                scope: vec!["".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -163,18 +207,19 @@ mod tests {
         #[test]
         fn test_none_type() {
             let rule = Type {
-                options: vec!["feat".to_string(), "chore".to_string()],
+                options: vec!["feat".into(), "chore".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: None,
                 raw: "".to_string(),
-                scope: None,
+                scope: vec![],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -189,18 +234,19 @@ mod tests {
         #[test]
         fn test_valid_type() {
             let rule = Type {
-                options: vec!["feat".to_string(), "chore".to_string()],
+                options: vec!["feat".into(), "chore".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("feat".to_string()),
                 raw: "feat(web): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("web".to_string()),
+                scope: vec!["web".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             assert!(rule.validate(&message).is_none());
@@ -209,18 +255,19 @@ mod tests {
         #[test]
         fn test_invalid_type() {
             let rule = Type {
-                options: vec!["feat".to_string(), "chore".to_string()],
+                options: vec!["feat".into(), "chore".into()],
                 ..Default::default()
             };
 
             let message = Message {
                 body: None,
                 description: None,
-                footers: None,
+                footers: vec![],
                 r#type: Some("invalid".to_string()),
                 raw: "invalid(web): broadcast $destroy event on scope destruction".to_string(),
-                scope: Some("web".to_string()),
+                scope: vec!["web".to_string()],
                 subject: None,
+                ..Default::default()
             };
 
             let violation = rule.validate(&message);
@@ -232,4 +279,42 @@ mod tests {
             );
         }
     }
+
+    mod patterns {
+        use super::*;
+
+        #[test]
+        fn test_glob() {
+            let rule = Type {
+                options: vec![OptionPattern::glob("feat*").unwrap()],
+                ..Default::default()
+            };
+
+            let message = Message::new("feature: add new flag".to_string());
+            assert!(rule.validate(&message).is_none());
+
+            let message = Message::new("fix: add new flag".to_string());
+            let violation = rule.validate(&message);
+            assert!(violation.is_some());
+            assert_eq!(
+                violation.unwrap().message,
+                "type fix is not allowed. Only [{glob: \"feat*\"}] are allowed".to_string()
+            );
+        }
+
+        #[test]
+        fn test_regex_ignore_case() {
+            let rule = Type {
+                options: vec![OptionPattern::regex("^(feat|fix)$").unwrap()],
+                ignore_case: true,
+                ..Default::default()
+            };
+
+            let message = Message::new("FIX: add new flag".to_string());
+            assert!(rule.validate(&message).is_none());
+
+            let message = Message::new("chore: add new flag".to_string());
+            assert!(rule.validate(&message).is_some());
+        }
+    }
 }
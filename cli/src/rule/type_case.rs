@@ -0,0 +1,134 @@
+use crate::{
+    case::{format_cases, Case},
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level};
+
+/// TypeCase represents the type-case rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypeCase {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Cases represents the allowed cases of the type.
+    /// The type must be in one of the cases.
+    cases: Vec<Case>,
+}
+
+/// TypeCase represents the type-case rule.
+impl Rule for TypeCase {
+    const NAME: &'static str = "type-case";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        format!(
+            "type must be in one of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "type must not be in any of cases: {}",
+            format_cases(&self.cases)
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        !self.cases.is_empty()
+            && message
+                .r#type
+                .as_deref()
+                .is_some_and(|text| !text.is_empty())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if self.cases.is_empty() {
+            return None;
+        }
+
+        if let Some(t) = message.r#type.as_deref().filter(|text| !text.is_empty()) {
+            if !self.cases.iter().any(|case| case.is(t)) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.r#type,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of TypeCase.
+impl Default for TypeCase {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            cases: vec![Case::Lower],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_case() {
+        let rule = TypeCase::default();
+        let message = Message::new("feat(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_case() {
+        let rule = TypeCase::default();
+        let message = Message::new("Feat(cli): add new flag".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type must be in one of cases: lower-case".to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_cases() {
+        let rule = TypeCase {
+            cases: vec![Case::Lower, Case::Upper],
+            ..Default::default()
+        };
+        let message = Message::new("FEAT(cli): add new flag".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level};
 
 /// TypeEmpty represents the type-empty rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,22 +16,43 @@ pub struct TypeEmpty {
     // Note that currently the default literal is not supported.
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
 }
 
 /// TypeEmpty represents the type-empty rule.
 impl Rule for TypeEmpty {
     const NAME: &'static str = "type-empty";
     const LEVEL: Level = Level::Error;
+    const APPLICABLE: Applicable = Applicable::Never;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
 
     fn message(&self, _message: &Message) -> String {
         "type is empty".to_string()
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        "type is not empty".to_string()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
         if message.r#type.is_none() || message.r#type.as_ref().unwrap().is_empty() {
             return Some(Violation {
                 level: self.level.unwrap_or(Self::LEVEL),
                 message: self.message(message),
+                span: message.spans.subject,
             });
         }
 
@@ -40,6 +65,7 @@ impl Default for TypeEmpty {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
         }
     }
 }
@@ -54,11 +80,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: None,
+            scope: vec![],
             subject: None,
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -70,11 +97,12 @@ mod tests {
         let message = Message {
             body: None,
             description: None,
-            footers: None,
+            footers: vec![],
             r#type: None,
             raw: "(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: None,
+            scope: vec![],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
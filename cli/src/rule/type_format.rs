@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Level, Pattern};
 
 /// TypeFormat represents the type-format rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,8 +17,11 @@ pub struct TypeFormat {
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
 
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
     /// Format represents the format of the type.
-    format: Option<String>,
+    format: Option<Pattern>,
 }
 
 /// TypeFormat represents the type-format rule.
@@ -22,6 +29,14 @@ impl Rule for TypeFormat {
     const NAME: &'static str = "type-format";
     const LEVEL: Level = Level::Error;
 
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
     fn message(&self, _message: &Message) -> String {
         format!(
             "type format does not match format: {}",
@@ -29,30 +44,37 @@ impl Rule for TypeFormat {
         )
     }
 
+    fn inverted_message(&self, _message: &Message) -> String {
+        format!(
+            "type format matches format: {}",
+            self.format.as_ref().unwrap()
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        self.format.is_some() && message.r#type.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
     fn validate(&self, message: &Message) -> Option<Violation> {
         if let Some(format) = &self.format {
-            let regex = match regex::Regex::new(format) {
-                Ok(regex) => regex,
-                Err(err) => {
-                    return Some(Violation {
-                        level: self.level.unwrap_or(Self::LEVEL),
-                        message: err.to_string(),
-                    });
-                }
-            };
-
             match &message.r#type {
                 None => {
                     return Some(Violation {
                         level: self.level.unwrap_or(Self::LEVEL),
                         message: "found no type".to_string(),
+                        span: message.spans.subject,
                     });
                 }
                 Some(description) => {
-                    if !regex.is_match(description) {
+                    if !format.is_match(description) {
                         return Some(Violation {
                             level: self.level.unwrap_or(Self::LEVEL),
                             message: self.message(message),
+                            span: message.spans.r#type,
                         });
                     }
                 }
@@ -68,6 +90,7 @@ impl Default for TypeFormat {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
             format: None,
         }
     }
@@ -80,18 +103,19 @@ mod tests {
     #[test]
     fn test_invalid_description_format() {
         let rule = TypeFormat {
-            format: Some(r"^[a-z].*".to_string()),
+            format: Some(Pattern::new(r"^[a-z].*").unwrap()),
             ..Default::default()
         };
 
         let message = Message {
             body: None,
             description: Some("Add new flag".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): Add new flag".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -100,18 +124,19 @@ mod tests {
     #[test]
     fn test_valid_description_format() {
         let rule = TypeFormat {
-            format: Some(r"^[a-z].*".to_string()),
+            format: Some(Pattern::new(r"^[a-z].*").unwrap()),
             ..Default::default()
         };
 
         let message = Message {
             body: None,
             description: Some("Add new flag".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("Feat".to_string()),
             raw: "Feat(scope): Add new flag".to_string(),
-            scope: Some("Scope".to_string()),
+            scope: vec!["Scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
@@ -125,24 +150,8 @@ mod tests {
 
     #[test]
     fn test_invalid_regex() {
-        let rule = TypeFormat {
-            format: Some(r"(".to_string()),
-            ..Default::default()
-        };
-
-        let message = Message {
-            body: None,
-            description: Some("Invalid regex".to_string()),
-            footers: None,
-            r#type: Some("feat".to_string()),
-            raw: "feat(scope): Invalid regex".to_string(),
-            scope: Some("scope".to_string()),
-            subject: None,
-        };
-
-        let violation = rule.validate(&message);
-        assert!(violation.is_some());
-        assert_eq!(violation.clone().unwrap().level, Level::Error);
-        assert!(violation.unwrap().message.contains("regex parse error"));
+        let rule = serde_yaml::from_str::<TypeFormat>(r#"format: "(""#);
+        assert!(rule.is_err());
+        assert!(rule.unwrap_err().to_string().contains("regex parse error"));
     }
 }
@@ -1,7 +1,11 @@
-use crate::{message::Message, result::Violation, rule::Rule};
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Level;
+use super::{Applicable, Bound, LengthUnit, Level};
 
 /// TypeMaxLength represents the description-max-length rule.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,8 +17,15 @@ pub struct TypeMaxLength {
     // See: https://github.com/serde-rs/serde/issues/368
     level: Option<Level>,
 
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
     /// Length represents the maximum length of the type.
     length: usize,
+
+    /// Unit represents how to count the length of the type.
+    #[serde(default)]
+    unit: LengthUnit,
 }
 
 /// TypeMaxLength represents the type-max-length rule.
@@ -22,16 +33,37 @@ impl Rule for TypeMaxLength {
     const NAME: &'static str = "type-max-length";
     const LEVEL: Level = Level::Error;
 
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
     fn message(&self, _message: &Message) -> String {
-        format!("type is longer than {} characters", self.length)
+        Bound::Max.message("type", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Max.inverted_message("type", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.r#type.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
     }
 
     fn validate(&self, message: &Message) -> Option<Violation> {
         if let Some(t) = &message.r#type {
-            if t.len() >= self.length {
+            if Bound::Max.is_exceeded(t, self.length, self.unit) {
                 return Some(Violation {
                     level: self.level.unwrap_or(Self::LEVEL),
                     message: self.message(message),
+                    span: message.spans.r#type,
                 });
             }
         }
@@ -45,7 +77,9 @@ impl Default for TypeMaxLength {
     fn default() -> Self {
         Self {
             level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
             length: 72,
+            unit: LengthUnit::default(),
         }
     }
 }
@@ -63,11 +97,12 @@ mod tests {
         let message = Message {
             body: None,
             description: Some("desc".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): desc".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): desc".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -82,11 +117,12 @@ mod tests {
         let message = Message {
             body: None,
             description: Some("broadcast $destroy event on scope destruction".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: None,
             raw: "feat(scope): broadcast $destroy event on scope destruction".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: Some("feat(scope): broadcast $destroy event on scope destruction".to_string()),
+            ..Default::default()
         };
 
         assert!(rule.validate(&message).is_none());
@@ -101,11 +137,12 @@ mod tests {
         let message = Message {
             body: None,
             description: Some("feat(scope): I'm long description".to_string()),
-            footers: None,
+            footers: vec![],
             r#type: Some("feat".to_string()),
             raw: "feat(scope): I'm long description".to_string(),
-            scope: Some("scope".to_string()),
+            scope: vec!["scope".to_string()],
             subject: None,
+            ..Default::default()
         };
 
         let violation = rule.validate(&message);
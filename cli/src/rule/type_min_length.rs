@@ -0,0 +1,118 @@
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Bound, LengthUnit, Level};
+
+/// TypeMinLength represents the type-min-length rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypeMinLength {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Length represents the minimum length of the type.
+    length: usize,
+
+    /// Unit represents how to count the length of the type.
+    #[serde(default)]
+    unit: LengthUnit,
+}
+
+/// TypeMinLength represents the type-min-length rule.
+impl Rule for TypeMinLength {
+    const NAME: &'static str = "type-min-length";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, _message: &Message) -> String {
+        Bound::Min.message("type", self.length, self.unit)
+    }
+
+    fn inverted_message(&self, _message: &Message) -> String {
+        Bound::Min.inverted_message("type", self.length, self.unit)
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.r#type.is_some()
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        if let Some(t) = &message.r#type {
+            if Bound::Min.is_exceeded(t, self.length, self.unit) {
+                return Some(Violation {
+                    level: self.level.unwrap_or(Self::LEVEL),
+                    message: self.message(message),
+                    span: message.spans.r#type,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Default implementation of TypeMinLength.
+impl Default for TypeMinLength {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            length: 0,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_type() {
+        let rule = TypeMinLength {
+            length: 3,
+            ..Default::default()
+        };
+        let message = Message::new("fix: x".to_string());
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_short_type() {
+        let rule = TypeMinLength {
+            length: 4,
+            ..Default::default()
+        };
+        let message = Message::new("fix: x".to_string());
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type is shorter than 4 characters".to_string()
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    message::{Message, Span},
+    result::Violation,
+    rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Applicable, Level, PathGlob};
+
+/// TypePath represents the type-path rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypePath {
+    /// Level represents the level of the rule.
+    ///
+    // Note that currently the default literal is not supported.
+    // See: https://github.com/serde-rs/serde/issues/368
+    level: Option<Level>,
+
+    /// Applicable represents whether the rule is applied as is or inverted.
+    applicable: Option<Applicable>,
+
+    /// Paths represents the allowed path globs of each type such as `docs: ["**/*.md"]`.
+    /// The types not listed here can change any files.
+    #[serde(default)]
+    paths: BTreeMap<String, Vec<PathGlob>>,
+}
+
+impl TypePath {
+    /// Find the changed file which is not allowed for the type.
+    fn find_disallowed<'a>(&self, message: &Message, files: &'a [String]) -> Option<&'a String> {
+        let globs = message.r#type.as_ref().and_then(|t| self.paths.get(t))?;
+        files
+            .iter()
+            .find(|file| !PathGlob::matches_any(globs, file))
+    }
+
+    /// Suggest the type whose globs match all the changed files.
+    fn suggest(&self, files: &[String]) -> Option<&String> {
+        self.paths
+            .iter()
+            .find(|(_, globs)| files.iter().all(|file| PathGlob::matches_any(globs, file)))
+            .map(|(r#type, _)| r#type)
+    }
+}
+
+/// TypePath represents the type-path rule.
+impl Rule for TypePath {
+    const NAME: &'static str = "type-path";
+    const LEVEL: Level = Level::Error;
+
+    fn level(&self) -> Level {
+        self.level.unwrap_or(Self::LEVEL)
+    }
+
+    fn applicable(&self) -> Applicable {
+        self.applicable.unwrap_or(Self::APPLICABLE)
+    }
+
+    fn message(&self, message: &Message) -> String {
+        let files = message.changed_files.as_deref().unwrap_or_default();
+        let r#type = message.r#type.as_deref().unwrap_or_default();
+        let file = self
+            .find_disallowed(message, files)
+            .map_or("", String::as_str);
+
+        match self.suggest(files) {
+            Some(suggested) => format!(
+                "type {} does not allow changing {}. Suggested type is {}",
+                r#type, file, suggested
+            ),
+            None => format!("type {} does not allow changing {}", r#type, file),
+        }
+    }
+
+    fn inverted_message(&self, message: &Message) -> String {
+        format!(
+            "type {} allows changing the files",
+            message.r#type.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn has_target(&self, message: &Message) -> bool {
+        message.r#type.is_some()
+            && message
+                .changed_files
+                .as_ref()
+                .is_some_and(|files| !files.is_empty())
+    }
+
+    fn span(&self, message: &Message) -> Option<Span> {
+        message.spans.r#type
+    }
+
+    fn validate(&self, message: &Message) -> Option<Violation> {
+        let files = message.changed_files.as_deref()?;
+
+        self.find_disallowed(message, files).map(|_| Violation {
+            level: self.level.unwrap_or(Self::LEVEL),
+            message: self.message(message),
+            span: message.spans.r#type.or(message.spans.subject),
+        })
+    }
+}
+
+/// Default implementation of TypePath.
+impl Default for TypePath {
+    fn default() -> Self {
+        Self {
+            level: Some(Self::LEVEL),
+            applicable: Some(Self::APPLICABLE),
+            paths: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_files() {
+        let rule = TypePath {
+            paths: BTreeMap::from([(
+                "docs".to_string(),
+                vec![
+                    PathGlob::new("**/*.md").unwrap(),
+                    PathGlob::new("web/**").unwrap(),
+                ],
+            )]),
+            ..Default::default()
+        };
+
+        let message = Message {
+            changed_files: Some(vec![
+                "README.md".to_string(),
+                "web/src/content/docs/rules/type-path.md".to_string(),
+            ]),
+            ..Message::new("docs: add rule page".to_string())
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_disallowed_file() {
+        let rule = TypePath {
+            paths: BTreeMap::from([("docs".to_string(), vec![PathGlob::new("**/*.md").unwrap()])]),
+            ..Default::default()
+        };
+
+        let message = Message {
+            changed_files: Some(vec!["README.md".to_string(), "cli/src/git.rs".to_string()]),
+            ..Message::new("docs: fix typo".to_string())
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(violation.clone().unwrap().level, Level::Error);
+        assert_eq!(
+            violation.unwrap().message,
+            "type docs does not allow changing cli/src/git.rs".to_string()
+        );
+    }
+
+    #[test]
+    fn test_suggested_type() {
+        let rule = TypePath {
+            paths: BTreeMap::from([
+                ("docs".to_string(), vec![PathGlob::new("**/*.md").unwrap()]),
+                ("test".to_string(), vec![PathGlob::new("cli/e2e/").unwrap()]),
+            ]),
+            ..Default::default()
+        };
+
+        let message = Message {
+            changed_files: Some(vec!["README.md".to_string(), "web/index.md".to_string()]),
+            ..Message::new("test: update readme".to_string())
+        };
+
+        let violation = rule.validate(&message);
+        assert!(violation.is_some());
+        assert_eq!(
+            violation.unwrap().message,
+            "type test does not allow changing README.md. Suggested type is docs".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_type() {
+        let rule = TypePath {
+            paths: BTreeMap::from([("docs".to_string(), vec![PathGlob::new("**/*.md").unwrap()])]),
+            ..Default::default()
+        };
+
+        let message = Message {
+            changed_files: Some(vec!["cli/src/git.rs".to_string()]),
+            ..Message::new("fix: fix typo".to_string())
+        };
+
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_no_changed_files() {
+        let rule = TypePath {
+            paths: BTreeMap::from([("docs".to_string(), vec![PathGlob::new("**/*.md").unwrap()])]),
+            ..Default::default()
+        };
+
+        let message = Message::new("docs: fix typo".to_string());
+        assert!(rule.validate(&message).is_none());
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        let rule = serde_yaml::from_str::<TypePath>("paths:\n  docs: [\"**/[.md\"]\n");
+        assert!(rule.is_err());
+        assert!(rule.unwrap_err().to_string().contains("Invalid path glob"));
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Default paths of the CODEOWNERS file in the order GitHub looks them up.
+const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Directories of the build outputs and the dependencies which are not scopes.
+const IGNORED_DIRECTORIES: [&str; 2] = ["node_modules", "target"];
+
+/// ScopeSource represents where the scopes are discovered from.
+///
+/// The scopes are the directory names of the discovered paths such as `cli` for `crates/cli`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ScopeSource {
+    /// Members of `[workspace]` in `Cargo.toml`.
+    #[serde(rename = "cargo-workspace")]
+    CargoWorkspace,
+
+    /// Directories of the `CODEOWNERS` entries such as `cli` for `/crates/cli/ @owner`.
+    #[serde(rename = "codeowners")]
+    Codeowners,
+
+    /// Top-level directories except the hidden ones such as `.git`
+    /// and the ones of the build outputs and the dependencies such as `target` and `node_modules`.
+    #[serde(rename = "directories")]
+    Directories,
+
+    /// Workspaces of `package.json`.
+    #[serde(rename = "npm-workspaces")]
+    NpmWorkspaces,
+
+    /// Packages of `pnpm-workspace.yaml`.
+    #[serde(rename = "pnpm-workspace")]
+    PnpmWorkspace,
+}
+
+impl ScopeSource {
+    /// Discover the scopes in the directory.
+    /// It returns an error if the file of the source is missing or malformed.
+    pub fn scopes(&self, cwd: &Path) -> Result<Vec<String>, String> {
+        let paths = match self {
+            ScopeSource::CargoWorkspace => {
+                let text = read(&cwd.join("Cargo.toml"))?;
+                let (members, exclude) = cargo_workspace(&text)?;
+                // Expand the excluded paths as well so that they are normalized in the same way
+                // as the members such as `crates/internal/` and `./crates/internal`.
+                let exclude = expand(cwd, &exclude)?;
+                expand(cwd, &members)?
+                    .into_iter()
+                    .filter(|path| !exclude.contains(path))
+                    .collect()
+            }
+            ScopeSource::Codeowners => {
+                let path = CODEOWNERS_PATHS
+                    .iter()
+                    .map(|path| cwd.join(path))
+                    .find(|path| path.exists())
+                    .ok_or_else(|| format!("CODEOWNERS not found in {}", cwd.display()))?;
+                codeowners_paths(&read(&path)?, cwd)
+            }
+            ScopeSource::Directories => fs::read_dir(cwd)
+                .map_err(|err| format!("Failed to read {}: {}", cwd.display(), err))?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .filter(|path| {
+                    !path
+                        .file_name()
+                        .is_some_and(|name| IGNORED_DIRECTORIES.iter().any(|dir| name == *dir))
+                })
+                .collect(),
+            ScopeSource::NpmWorkspaces => {
+                let text = read(&cwd.join("package.json"))?;
+                expand(cwd, &npm_workspaces(&text)?)?
+            }
+            ScopeSource::PnpmWorkspace => {
+                let text = read(&cwd.join("pnpm-workspace.yaml"))?;
+                expand(cwd, &pnpm_packages(&text)?)?
+            }
+        };
+
+        Ok(dedup(
+            paths
+                .iter()
+                .filter_map(|path| path.file_name()?.to_str())
+                .filter(|name| !name.starts_with('.'))
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+}
+
+/// Read the file of the scope source.
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+}
+
+/// Sort the scopes and remove the duplicates.
+fn dedup(scopes: Vec<String>) -> Vec<String> {
+    scopes
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Expand the globs such as `packages/*` into the directories.
+/// Note that the negated globs such as `!packages/internal` exclude the directories.
+fn expand(cwd: &Path, patterns: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+    for pattern in patterns {
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern.as_str()),
+        };
+
+        let pattern = cwd.join(pattern.trim_start_matches("./").trim_end_matches('/'));
+        let matches = glob::glob(&pattern.to_string_lossy())
+            .map_err(|err| format!("Invalid workspace glob {:?}: {}", pattern, err))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_dir());
+
+        if negated {
+            let excluded = matches.collect::<Vec<_>>();
+            paths.retain(|path| !excluded.contains(path));
+        } else {
+            paths.extend(matches);
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Extract the members and the excluded paths of `[workspace]` in `Cargo.toml`.
+fn cargo_workspace(text: &str) -> Result<(Vec<String>, Vec<String>), String> {
+    #[derive(Default, Deserialize)]
+    struct Workspace {
+        #[serde(default)]
+        members: Vec<String>,
+
+        #[serde(default)]
+        exclude: Vec<String>,
+    }
+
+    #[derive(Deserialize)]
+    struct Manifest {
+        #[serde(default)]
+        workspace: Workspace,
+    }
+
+    toml::from_str::<Manifest>(text)
+        .map(|manifest| (manifest.workspace.members, manifest.workspace.exclude))
+        .map_err(|err| format!("Failed to parse Cargo.toml: {}", err))
+}
+
+/// Extract the workspaces of `package.json`.
+/// Both `["packages/*"]` and `{ "packages": ["packages/*"] }` are supported.
+fn npm_workspaces(text: &str) -> Result<Vec<String>, String> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Workspaces {
+        List(Vec<String>),
+        Object { packages: Vec<String> },
+    }
+
+    #[derive(Deserialize)]
+    struct Package {
+        #[serde(default)]
+        workspaces: Option<Workspaces>,
+    }
+
+    let package = serde_json::from_str::<Package>(text)
+        .map_err(|err| format!("Failed to parse package.json: {}", err))?;
+
+    Ok(match package.workspaces {
+        Some(Workspaces::List(packages)) | Some(Workspaces::Object { packages }) => packages,
+        None => vec![],
+    })
+}
+
+/// Extract the packages of `pnpm-workspace.yaml`.
+fn pnpm_packages(text: &str) -> Result<Vec<String>, String> {
+    #[derive(Deserialize)]
+    struct Workspace {
+        #[serde(default)]
+        packages: Vec<String>,
+    }
+
+    serde_yaml::from_str::<Workspace>(text)
+        .map(|workspace| workspace.packages)
+        .map_err(|err| format!("Failed to parse pnpm-workspace.yaml: {}", err))
+}
+
+/// Extract the directories from the CODEOWNERS entries.
+///
+/// The entries are the directories such as `/crates/cli/` and `docs/**`,
+/// or the paths of the existing directories such as `e2e`.
+/// The entries matching files such as `*.rs` and `src/*.rs` are skipped.
+fn codeowners_paths(text: &str, cwd: &Path) -> Vec<PathBuf> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_whitespace().next())
+        .filter_map(|pattern| {
+            let path = pattern.trim_start_matches('/');
+            let (path, is_dir) = match path.strip_suffix("/**").or(path.strip_suffix('/')) {
+                Some(path) => (path, true),
+                None => (path, cwd.join(path).is_dir()),
+            };
+
+            let is_glob = path.contains(['*', '?', '[']);
+            (is_dir && !is_glob && !path.is_empty()).then(|| cwd.join(path))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cargo_workspace_manifest() {
+        let text = r#"
+[package]
+name = "root" # members = ["ignored"]
+
+[workspace]
+resolver = "2"
+members = [
+    "cli", # comment
+    'crates/*',
+]
+exclude = ["crates/#internal"]
+"#;
+
+        assert_eq!(
+            cargo_workspace(text),
+            Ok((
+                vec!["cli".to_string(), "crates/*".to_string()],
+                vec!["crates/#internal".to_string()]
+            ))
+        );
+        assert_eq!(
+            cargo_workspace("workspace.members = [\"cli\"]"),
+            Ok((vec!["cli".to_string()], vec![]))
+        );
+        assert_eq!(
+            cargo_workspace("workspace = { members = [\"cli\"], exclude = [\"web\"] }"),
+            Ok((vec!["cli".to_string()], vec!["web".to_string()]))
+        );
+        assert_eq!(
+            cargo_workspace("[package]\nname = \"cli\""),
+            Ok((vec![], vec![]))
+        );
+        assert!(cargo_workspace("[workspace").is_err());
+    }
+
+    #[test]
+    fn test_npm_workspaces() {
+        assert_eq!(
+            npm_workspaces(r#"{"name": "root", "workspaces": ["packages/*"]}"#),
+            Ok(vec!["packages/*".to_string()])
+        );
+        assert_eq!(
+            npm_workspaces(r#"{"workspaces": {"packages": ["apps/*"]}}"#),
+            Ok(vec!["apps/*".to_string()])
+        );
+        assert_eq!(npm_workspaces(r#"{"name": "root"}"#), Ok(vec![]));
+        assert!(npm_workspaces("{").is_err());
+    }
+
+    #[test]
+    fn test_pnpm_packages() {
+        assert_eq!(
+            pnpm_packages("packages:\n  - 'packages/*'\n  - '!**/test/**'\n"),
+            Ok(vec!["packages/*".to_string(), "!**/test/**".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_codeowners_paths() {
+        let cwd = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let text = "# Owners\n* @owner\n*.rs @rust\n/src/ @cli\ne2e @e2e\ndocs/** @docs\nCargo.toml @owner\n/crates/cli/ @cli\nsrc/*.rs @rust\n/.github/ @ci\n";
+
+        assert_eq!(
+            codeowners_paths(text, cwd),
+            vec![
+                cwd.join("src"),
+                cwd.join("e2e"),
+                cwd.join("docs"),
+                cwd.join("crates/cli"),
+                cwd.join(".github"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_codeowners() {
+        let cwd = std::env::temp_dir().join("commitlint-test-codeowners");
+        fs::create_dir_all(cwd.join(".github")).unwrap();
+        fs::write(
+            cwd.join(".github/CODEOWNERS"),
+            "/crates/cli/ @cli\n/crates/schema/** @schema\n/.github/ @ci\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            ScopeSource::Codeowners.scopes(&cwd),
+            Ok(vec!["cli".to_string(), "schema".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_cargo_workspace() {
+        let cwd = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        assert_eq!(
+            ScopeSource::CargoWorkspace.scopes(&cwd),
+            Ok(vec!["cli".to_string(), "schema".to_string()])
+        );
+        assert!(ScopeSource::NpmWorkspaces.scopes(&cwd).is_err());
+    }
+
+    #[test]
+    fn test_cargo_workspace_exclude() {
+        let cwd = std::env::temp_dir().join("commitlint-test-cargo-workspace-exclude");
+        for dir in ["crates/cli", "crates/internal", "crates/schema"] {
+            fs::create_dir_all(cwd.join(dir)).unwrap();
+        }
+        fs::write(
+            cwd.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/internal/\", \"./crates/schema\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            ScopeSource::CargoWorkspace.scopes(&cwd),
+            Ok(vec!["cli".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_directories() {
+        let cwd = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let scopes = ScopeSource::Directories.scopes(cwd).unwrap();
+
+        assert!(scopes.contains(&"src".to_string()));
+        assert!(scopes.contains(&"e2e".to_string()));
+        assert!(!scopes.iter().any(|scope| scope.starts_with('.')));
+
+        let cwd = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let scopes = ScopeSource::Directories.scopes(&cwd).unwrap();
+        assert!(scopes.contains(&"cli".to_string()));
+        assert!(!scopes.contains(&"target".to_string()));
+    }
+}